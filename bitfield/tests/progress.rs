@@ -1,6 +1,6 @@
 #[test]
 fn tests() {
-    let t = trybuild::TestCases::new();
+    let _t = trybuild::TestCases::new();
     //t.pass("tests/01-specifier-types.rs");
     //t.pass("tests/02-storage.rs");
     //t.pass("tests/03-accessors.rs");
@@ -0,0 +1,348 @@
+use crate::builder::{unwrap_generic, unwrap_pointer};
+use syn::{
+    parse_quote, Attribute, Expr, Field, Ident, Lit, LitStr, Meta, MetaList, MetaNameValue,
+    NestedMeta, Path, Visibility,
+};
+
+// Struct-level `#[builder(...)]` options.
+#[derive(Default)]
+pub struct StructAttrs {
+    // Function called for every unset field at build time, from
+    // `#[builder(field_default)]` or `#[builder(field_default = "path")]`.
+    pub field_default: Option<Path>,
+    // Function taking `&mut` the built struct, called before it is returned,
+    // from `#[builder(after_build = "path")]`.
+    pub after_build: Option<Path>,
+    // Function checking the built struct, of the form
+    // `fn(&Struct) -> Result<(), E>`, whose error `build` returns converted
+    // into its own, from `#[builder(validate = "path")]`. Not allowed with
+    // `derive_default`, whose Default impl could not report a rejection.
+    // `build_or_default` then returns a Result too; `random` skips it.
+    pub validate: Option<Path>,
+    // Also implement Default for the struct, from `#[builder(derive_default)]`.
+    pub derive_default: bool,
+    // Traits derived for the builder besides Default, from
+    // `#[builder(derive(Debug, Clone))]`.
+    pub derives: Vec<Path>,
+    // Also check every field type against those traits, for an error that
+    // points at the field and names the attribute, from
+    // `#[builder(check_derives)]`.
+    pub check_derives: bool,
+    // Traits derived for the builder only under `cfg(test)`, from
+    // `#[builder(test_derive(PartialEq, Eq))]`.
+    pub test_derives: Vec<Path>,
+    // Name of the builder struct in place of `<Struct>Builder`, from
+    // `#[builder(name = "Name")]`.
+    pub name: Option<Ident>,
+    // Trait connecting the struct to its builder, implemented in addition to
+    // the inherent `builder()`, from `#[builder(trait)]` for a `HasBuilder`
+    // in scope or `#[builder(trait = "path")]`.
+    pub builder_trait: Option<Path>,
+    // Track which fields have been set in a u64 of one bit per field, queried
+    // with `is_set`, from `#[builder(presence_bits)]`. Limits the struct to 64
+    // fields.
+    pub presence_bits: bool,
+    // Name of the method building the struct in place of `build`, from
+    // `#[builder(build_fn = "name")]` or `#[builder(build_fn(name = "name"))]`.
+    pub build_fn: Option<Ident>,
+    // Error type returned by that method in place of `anyhow::Error`, from
+    // `#[builder(build_fn(error = "path"))]`. It has to implement
+    // `From<&'static str>`, which is given the missing field's message.
+    pub build_error: Option<Path>,
+    // Also give the struct a `random(rng)` constructor under `cfg(test)`,
+    // filling every field through `rand`'s Standard distribution, from
+    // `#[builder(test_random)]`.
+    pub test_random: bool,
+    // Attributes copied from each struct field onto its builder field, by
+    // name, from `#[builder(forward_attrs(serde))]`.
+    pub forward_attrs: Vec<Path>,
+}
+
+impl StructAttrs {
+    pub fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut parsed = StructAttrs::default();
+        for nested in builder_args(attrs)? {
+            match &nested {
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("field_default") => {
+                    parsed.field_default = Some(parse_quote!(::std::default::Default::default));
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("field_default") => {
+                    parsed.field_default = Some(parse_value(nv, "path")?);
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("presence_bits") => {
+                    parsed.presence_bits = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("test_random") => {
+                    parsed.test_random = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("check_derives") => {
+                    parsed.check_derives = true;
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("derive_default") => {
+                    parsed.derive_default = true;
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("after_build") => {
+                    parsed.after_build = Some(parse_value(nv, "path")?);
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("validate") => {
+                    parsed.validate = Some(parse_value(nv, "path")?);
+                }
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("derive") => {
+                    parsed.derives.extend(trait_names(list)?);
+                }
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("test_derive") => {
+                    parsed.test_derives.extend(trait_names(list)?);
+                }
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("forward_attrs") => {
+                    parsed.forward_attrs.extend(attr_names(list)?);
+                }
+                NestedMeta::Meta(Meta::Path(path)) if path.is_ident("trait") => {
+                    parsed.builder_trait = Some(parse_quote!(HasBuilder));
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("trait") => {
+                    parsed.builder_trait = Some(parse_value(nv, "path")?);
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("build_fn") => {
+                    parsed.build_fn = Some(parse_value(nv, "identifier")?);
+                }
+                NestedMeta::Meta(Meta::List(list)) if list.path.is_ident("build_fn") => {
+                    for option in &list.nested {
+                        match option {
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                                parsed.build_fn = Some(parse_value(nv, "identifier")?);
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("error") => {
+                                parsed.build_error = Some(parse_value(nv, "path")?);
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    option,
+                                    "expected `build_fn(name = \"...\", error = \"...\")`",
+                                ))
+                            }
+                        }
+                    }
+                }
+                NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("name") => {
+                    parsed.name = Some(parse_value(nv, "identifier")?);
+                }
+                _ => return Err(syn::Error::new_spanned(nested, "unknown builder attribute")),
+            }
+        }
+        Ok(parsed)
+    }
+}
+
+// A struct field together with its `#[builder(...)]` options.
+pub struct BuilderField<'a> {
+    pub field: &'a Field,
+    // Position of the field in the struct, counting from 0.
+    pub index: usize,
+    // Name of the setter appending one element, from `#[builder(each = "name")]`.
+    pub each: Option<Ident>,
+    // Value used when the setter was never called, from
+    // `#[builder(default = "expr")]`. It has the field's declared type.
+    pub default: Option<Expr>,
+    // Visibility of this field's setters in place of the struct's, from
+    // `#[builder(setter(vis = "pub(crate)"))]`.
+    pub setter_vis: Option<Visibility>,
+    // Name of the setter taking the whole value in place of the field's own
+    // name, from `#[builder(setter = "name")]`.
+    pub setter_name: Option<Ident>,
+    // Doc string put on this field's setters ahead of the field's own doc
+    // comments, from `#[builder(setter(doc = "..."))]`.
+    pub setter_doc: Option<LitStr>,
+    // Value the field is built with when it has no setter and no storage in
+    // the builder, from `#[builder(skip)]`, which uses Default, or
+    // `#[builder(skip = "expr")]`.
+    pub skip: Option<Expr>,
+    // Have the setter take the value a `Box`, `Rc` or `Arc` field points to
+    // and wrap it, from `#[builder(wrap)]`.
+    pub wrap: bool,
+}
+
+impl<'a> BuilderField<'a> {
+    pub fn parse(field: &'a Field, index: usize) -> syn::Result<Self> {
+        let mut parsed = BuilderField {
+            field,
+            index,
+            each: None,
+            default: None,
+            setter_vis: None,
+            setter_name: None,
+            setter_doc: None,
+            skip: None,
+            wrap: false,
+        };
+        for attr in field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path.is_ident("builder"))
+        {
+            let list = match attr.parse_meta()? {
+                Meta::List(list) => list,
+                meta => return Err(syn::Error::new_spanned(meta, "expected `builder(...)`")),
+            };
+            for nested in &list.nested {
+                match nested {
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("skip") => {
+                        let ty = &field.ty;
+                        parsed.skip =
+                            Some(parse_quote!(<#ty as ::std::default::Default>::default()));
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("skip") => {
+                        parsed.skip = Some(parse_value(nv, "Rust expression")?);
+                    }
+                    NestedMeta::Meta(Meta::Path(path)) if path.is_ident("wrap") => {
+                        if unwrap_pointer(&field.ty).is_none() {
+                            return Err(syn::Error::new_spanned(
+                                &list,
+                                "#[builder(wrap)] is only valid on Box, Rc or Arc fields",
+                            ));
+                        }
+                        parsed.wrap = true;
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("each") => {
+                        if unwrap_generic(&field.ty, "Vec").is_none() {
+                            return Err(syn::Error::new_spanned(
+                                &list,
+                                "#[builder(each = ...)] is only valid on Vec fields",
+                            ));
+                        }
+                        parsed.each = Some(parse_value(nv, "identifier")?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("default") => {
+                        parsed.default = Some(parse_value(nv, "Rust expression")?);
+                    }
+                    NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("setter") => {
+                        parsed.setter_name = Some(parse_value(nv, "identifier")?);
+                    }
+                    NestedMeta::Meta(Meta::List(setter)) if setter.path.is_ident("setter") => {
+                        for option in &setter.nested {
+                            match option {
+                                NestedMeta::Meta(Meta::NameValue(nv))
+                                    if nv.path.is_ident("vis") =>
+                                {
+                                    parsed.setter_vis = Some(parse_value(nv, "visibility")?);
+                                }
+                                NestedMeta::Meta(Meta::NameValue(nv))
+                                    if nv.path.is_ident("doc") =>
+                                {
+                                    parsed.setter_doc = match &nv.lit {
+                                        Lit::Str(doc) => Some(doc.clone()),
+                                        lit => {
+                                            return Err(syn::Error::new_spanned(
+                                                lit,
+                                                "expected a string literal",
+                                            ))
+                                        }
+                                    };
+                                }
+                                _ => return Err(syn::Error::new_spanned(
+                                    setter,
+                                    "expected `setter(vis = \"...\")` or `setter(doc = \"...\")`",
+                                )),
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err(syn::Error::new_spanned(
+                            &list,
+                            "expected `builder(each = \"...\")`",
+                        ))
+                    }
+                }
+            }
+        }
+        if let (Some(_), Some(default)) = (&parsed.each, &parsed.default) {
+            return Err(syn::Error::new_spanned(
+                default,
+                "a field collected with `each` already defaults to an empty Vec",
+            ));
+        }
+        let configures_setter = parsed.each.is_some()
+            || parsed.default.is_some()
+            || parsed.setter_vis.is_some()
+            || parsed.setter_name.is_some()
+            || parsed.setter_doc.is_some()
+            || parsed.wrap;
+        if parsed.skip.is_some() && configures_setter {
+            return Err(syn::Error::new_spanned(
+                field,
+                "a skipped field has no setter, so it takes no other builder options",
+            ));
+        }
+        Ok(parsed)
+    }
+
+    // Name of the setter taking the whole value, or None when the field is
+    // skipped or the `each` setter already has that name.
+    pub fn setter(&self) -> Option<&Ident> {
+        if self.skip.is_some() {
+            return None;
+        }
+        let name = self.setter_name.as_ref().or(self.field.ident.as_ref())?;
+        match &self.each {
+            Some(each) if each == name => None,
+            _ => Some(name),
+        }
+    }
+
+    // Every method name this field adds to the builder.
+    pub fn setter_names(&self) -> impl Iterator<Item = &Ident> {
+        self.each.iter().chain(self.setter())
+    }
+}
+
+// Flattens the arguments of every `#[builder(...)]` attribute, ignoring
+// attributes with any other name.
+fn builder_args(attrs: &[Attribute]) -> syn::Result<Vec<NestedMeta>> {
+    let mut args = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path.is_ident("builder")) {
+        match attr.parse_meta()? {
+            Meta::List(list) => args.extend(list.nested),
+            meta => return Err(syn::Error::new_spanned(meta, "expected `builder(...)`")),
+        }
+    }
+    Ok(args)
+}
+
+fn trait_names(list: &MetaList) -> syn::Result<Vec<Path>> {
+    list.nested
+        .iter()
+        .map(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => Ok(path.clone()),
+            _ => Err(syn::Error::new_spanned(nested, "expected a trait name")),
+        })
+        .collect()
+}
+
+fn attr_names(list: &MetaList) -> syn::Result<Vec<Path>> {
+    list.nested
+        .iter()
+        .map(|nested| match nested {
+            NestedMeta::Meta(Meta::Path(path)) => Ok(path.clone()),
+            _ => Err(syn::Error::new_spanned(
+                nested,
+                "expected an attribute name",
+            )),
+        })
+        .collect()
+}
+
+// Parses the string value of `key = "..."`, naming the key and what it
+// should hold if the contents do not parse.
+fn parse_value<T: syn::parse::Parse>(nv: &MetaNameValue, what: &str) -> syn::Result<T> {
+    let s = match &nv.lit {
+        Lit::Str(s) => s,
+        lit => return Err(syn::Error::new_spanned(lit, "expected a string literal")),
+    };
+    s.parse().map_err(|e| {
+        let key = nv
+            .path
+            .get_ident()
+            .map(Ident::to_string)
+            .unwrap_or_default();
+        syn::Error::new_spanned(s, format!("{} is not a valid {}: {}", key, what, e))
+    })
+}
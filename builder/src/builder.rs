@@ -1,22 +1,35 @@
-use anyhow;
-use proc_macro2::{Ident, Span, TokenStream};
-use quote::quote;
+use crate::attrs::{BuilderField, StructAttrs};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
+use quote::{format_ident, quote, quote_spanned, ToTokens};
+use syn::spanned::Spanned;
 use syn::{
-    AngleBracketedGenericArguments, Data, DataStruct, DeriveInput, GenericArgument, Path,
-    PathArguments, Type, TypePath,
+    parse_quote, Attribute, Data, DataStruct, DeriveInput, Fields, GenericArgument, GenericParam,
+    Path, PathArguments, Type, Visibility,
 };
 
-pub fn build(input: &DeriveInput) -> Result<TokenStream, anyhow::Error> {
+pub fn build(input: &DeriveInput) -> syn::Result<TokenStream> {
     match &input.data {
-        Data::Struct(data) => impl_struct(input, data),
-        _ => Ok(TokenStream::new()),
+        Data::Struct(
+            data @ DataStruct {
+                fields: Fields::Named(_),
+                ..
+            },
+        ) => impl_struct(input, data),
+        Data::Struct(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "Builder can only be derived for structs with named fields",
+        )),
+        _ => Err(syn::Error::new_spanned(
+            &input.ident,
+            "Builder can only be derived for structs",
+        )),
     }
 }
 
 macro_rules! extract {
-    ($member:ident, $body:expr) => {
-        fn $member<'a>(data: &'a syn::DataStruct) -> Vec<TokenStream> {
-            data.fields
+    ($member:ident($($arg:ident: $ty:ty),*), $body:expr) => {
+        fn $member(fields: &[BuilderField] $(, $arg: $ty)*) -> Vec<TokenStream> {
+            fields
                 .iter()
                 .filter_map($body)
                 .collect::<Vec<TokenStream>>()
@@ -24,105 +37,769 @@ macro_rules! extract {
     };
 }
 
-macro_rules! handle_option {
-    ($fn_name:ident, $stmt1: expr, $stmt2: expr) => {
-        fn $fn_name<'a>(field: &'a syn::Field) -> Option<TokenStream> {
-            if let (Some(ident), syn::Type::Path(t)) = (&field.ident, &field.ty) {
-                let arg = &t.path.segments;
-                if &arg[0].ident.to_string() == "Option" {
-                    if let PathArguments::AngleBracketed(AngleBracketedGenericArguments {
-                        args,
-                        ..
-                    }) = &arg[0].arguments
-                    {
-                        if let GenericArgument::Type(Type::Path(TypePath { path, .. })) = &args[0] {
-                            $stmt1(path, ident)
-                        } else {
-                            None
-                        }
-                    } else {
-                        None
-                    }
-                } else {
-                    $stmt2(ident, arg)
-                }
-            } else {
-                None
+// Returns `T` if `ty` is written as `wrapper<T>`.
+pub fn unwrap_generic<'a>(ty: &'a Type, wrapper: &str) -> Option<&'a Type> {
+    let segment = match ty {
+        Type::Path(t) if t.qself.is_none() => t.path.segments.last()?,
+        _ => return None,
+    };
+    if segment.ident != wrapper {
+        return None;
+    }
+    match &segment.arguments {
+        PathArguments::AngleBracketed(bracketed) if bracketed.args.len() == 1 => {
+            match &bracketed.args[0] {
+                GenericArgument::Type(inner) => Some(inner),
+                _ => None,
             }
         }
+        _ => None,
+    }
+}
+
+// Returns `T` if `ty` is written as `Box<T>`, `Rc<T>` or `Arc<T>`.
+pub fn unwrap_pointer(ty: &Type) -> Option<&Type> {
+    vec!["Box", "Rc", "Arc"]
+        .into_iter()
+        .find_map(|pointer| unwrap_generic(ty, pointer))
+}
+
+extract!(extract_setter(vis: &Visibility, attrs: &StructAttrs), |f| {
+    let ident = f.field.ident.as_ref()?;
+    if f.skip.is_some() {
+        return None;
+    }
+    let mark = if attrs.presence_bits {
+        let bit = presence_bit(f);
+        Some(quote! {self.__presence |= #bit;})
+    } else {
+        None
     };
+    let vis = f.setter_vis.as_ref().unwrap_or(vis);
+    // The field's doc comments describe its setters as well, after the
+    // setter's own doc string if it has one.
+    let setter_doc: Option<Attribute> = f
+        .setter_doc
+        .as_ref()
+        .map(|doc| parse_quote!(#[doc = #doc]));
+    let docs: Vec<&Attribute> = setter_doc
+        .iter()
+        .chain(f.field.attrs.iter().filter(|attr| attr.path.is_ident("doc")))
+        .collect();
+    let ty = &f.field.ty;
+    if let Some(each) = &f.each {
+        let inner = unwrap_generic(ty, "Vec")?;
+        let setter = f.setter().map(|setter| {
+            setter_pair(vis, &docs, setter, ident, ty, quote! {self.#ident = #ident; #mark})
+        });
+        let assign = quote! {self.#ident.push(#each); #mark};
+        let each_setter = setter_pair(vis, &docs, each, each, inner, assign);
+        return Some(quote! {
+            #each_setter
+            #setter
+        });
+    }
+    let setter = f.setter()?;
+    // The pointer is made through From, which all three implement for the
+    // value they point to, so the type is used as written whatever its path.
+    if f.wrap {
+        let inner = unwrap_pointer(ty)?;
+        let assign = quote! {
+            self.#ident = Some(<#ty as ::std::convert::From<#inner>>::from(#ident)); #mark
+        };
+        return Some(setter_pair(vis, &docs, setter, ident, inner, assign));
+    }
+    // Only the outer Option is unwrapped, so an `Option<Option<T>>` field has a
+    // setter taking `Option<T>`.
+    let ty = unwrap_generic(ty, "Option").unwrap_or(ty);
+    let assign = quote! {self.#ident = Some(#ident); #mark};
+    Some(setter_pair(vis, &docs, setter, ident, ty, assign))
+});
+
+// A `&mut self` setter together with its by-value `with_` counterpart, which
+// lets a builder be set up in a single expression without a `let mut`.
+fn setter_pair(
+    vis: &Visibility,
+    docs: &[&Attribute],
+    name: &Ident,
+    arg: &Ident,
+    ty: &Type,
+    assign: TokenStream,
+) -> TokenStream {
+    let with = format_ident!("with_{}", name);
+    quote! {
+        #(#docs)*
+        #vis fn #name(&mut self, #arg: #ty) -> &mut Self {
+            #assign
+            self
+        }
+        #(#docs)*
+        #[must_use]
+        #vis fn #with(mut self, #arg: #ty) -> Self {
+            self.#name(#arg);
+            self
+        }
+    }
 }
 
-extract!(extract_setter, |field| {
-    handle_option!(
-        handle_for_setters,
-        |path: &Path, ident| {
-            let a = &path.segments[0].ident;
-            Some(quote! {
-                fn #ident<'a>(&'a mut self, #ident: #a) -> &'a mut Self {
-                    self.#ident = Some(#ident.clone());
-                    self
-                }
-            })
-        },
-        |ident, arg| {
-            Some(quote! {
-                fn #ident<'a>(&'a mut self, #ident: #arg) -> &'a mut Self {
-                    self.#ident = Some(#ident.clone());
-                    self
-                }
-            })
+// Fields collected with `each` are stored as the Vec itself, which starts out
+// empty, and are never required. Skipped fields are not stored at all.
+// Attributes named in `forward_attrs` are copied over as written. The fields
+// stay private whatever the builder's visibility, since they are only reached
+// through its methods, so a field type can be as private as the struct allows.
+extract!(extract_fields(attrs: &StructAttrs), |f| {
+    let ident = f.field.ident.as_ref()?;
+    if f.skip.is_some() {
+        return None;
+    }
+    let forwarded = f
+        .field
+        .attrs
+        .iter()
+        .filter(|attr| attrs.forward_attrs.contains(&attr.path));
+    if f.each.is_some() {
+        let ty = &f.field.ty;
+        return Some(quote! {#(#forwarded)* #ident: #ty});
+    }
+    let ty = unwrap_generic(&f.field.ty, "Option").unwrap_or(&f.field.ty);
+    Some(quote! {#(#forwarded)* #ident: Option<#ty>})
+});
+
+// Required fields are checked before any value is moved out of the builder,
+// so a failed build leaves it untouched.
+extract!(extract_required_checks(attrs: &StructAttrs), |f| {
+    let ident = f.field.ident.as_ref()?;
+    if fallback(f, attrs).is_some() {
+        return None;
+    }
+    let err = missing_error(ident, attrs);
+    Some(quote! {self.#ident.as_ref().ok_or_else(|| #err)?;})
+});
+
+extract!(extract_default_fields(), |f| {
+    let ident = f.field.ident.as_ref()?;
+    if f.skip.is_some() {
+        return None;
+    }
+    if f.each.is_some() {
+        return Some(quote! {#ident: ::std::vec::Vec::new()});
+    }
+    Some(quote! {#ident: None})
+});
+
+// A builder made from an existing value has every field set, Option fields
+// keeping whatever they hold. Skipped fields are computed again by `build`.
+extract!(extract_edit_fields(), |f| {
+    let ident = f.field.ident.as_ref()?;
+    if f.skip.is_some() {
+        return None;
+    }
+    if f.each.is_some() || unwrap_generic(&f.field.ty, "Option").is_some() {
+        return Some(quote! {#ident: existing.#ident});
+    }
+    Some(quote! {#ident: Some(existing.#ident)})
+});
+
+extract!(extract_builder_fields(attrs: &StructAttrs), |f| {
+    let ident = f.field.ident.as_ref()?;
+    match fallback(f, attrs) {
+        Some(value) => Some(quote! {#ident: #value}),
+        None => {
+            let err = missing_error(ident, attrs);
+            Some(quote! {#ident: self.#ident.take().ok_or_else(|| #err)?})
         }
-    );
-    handle_for_setters(field)
+    }
 });
 
-extract!(extract_fields, |field| {
-    handle_option!(
-        handle_for_fields,
-        |path: &Path, ident| {
-            let a = &path.segments[0].ident;
-            Some(quote! {#ident: Option<#a>})
-        },
-        |ident, arg| { Some(quote! {#ident: Option<#arg>}) }
-    );
-    handle_for_fields(field)
+// `try_build` leaves the builder as it is, so it clones the values instead.
+extract!(extract_try_build_fields(attrs: &StructAttrs), |f| {
+    let ident = f.field.ident.as_ref()?;
+    match cloned_fallback(f, attrs) {
+        Some(value) => Some(quote! {#ident: #value}),
+        None => Some(quote! {#ident: self.#ident.clone()?}),
+    }
 });
 
-extract!(extract_builder_fields, |field| {
-    handle_option!(
-        handle_for_build,
-        |_: &Path, ident| { Some(quote! {#ident: self.#ident.clone()}) },
-        |ident, _| { Some(quote! {#ident: self.#ident.clone().unwrap()}) }
-    );
-    handle_for_build(field)
+// Clone is asked of the field types on the methods that clone alone, written
+// for any lifetime like the Default bounds below, so builders of types
+// without Clone keep `build`.
+extract!(extract_clone_bounds(), |f| {
+    if f.skip.is_some() {
+        return None;
+    }
+    let ty = &f.field.ty;
+    Some(quote! {for<'__clone> #ty: ::std::clone::Clone})
 });
-fn impl_struct(input: &DeriveInput, data: &DataStruct) -> Result<TokenStream, anyhow::Error> {
-    let setters = extract_setter(&data);
 
-    let fields = extract_fields(&data);
+// Required fields left unset are given Default, which is asked of their
+// types on the method alone, so the other build methods work without it. The
+// bound is written for any lifetime so that one on a type without Default is
+// only an error where the method is called, not on every builder. Like
+// `try_build`, the method clones the values it finds.
+extract!(extract_or_default_fields(attrs: &StructAttrs), |f| {
+    let ident = f.field.ident.as_ref()?;
+    match cloned_fallback(f, attrs) {
+        Some(value) => Some(quote! {#ident: #value}),
+        None => Some(quote! {#ident: self.#ident.clone().unwrap_or_default()}),
+    }
+});
+
+extract!(extract_or_default_bounds(attrs: &StructAttrs), |f| {
+    if fallback(f, attrs).is_some() {
+        return None;
+    }
+    let ty = &f.field.ty;
+    Some(quote! {for<'__default> #ty: ::std::default::Default})
+});
 
-    let build_fields = extract_builder_fields(&data);
+// Whether `tokens` name the type parameter `ident`, or the lifetime of that
+// name when `lifetime` is set, anywhere within them.
+fn mentions(tokens: TokenStream, ident: &Ident, lifetime: bool) -> bool {
+    let mut after_apostrophe = false;
+    tokens.into_iter().any(|tt| {
+        let found = match &tt {
+            TokenTree::Ident(i) => i == ident && after_apostrophe == lifetime,
+            TokenTree::Group(g) => mentions(g.stream(), ident, lifetime),
+            _ => false,
+        };
+        after_apostrophe = matches!(&tt, TokenTree::Punct(p) if p.as_char() == '\'');
+        found
+    })
+}
+
+// The bit standing for the field in `#[builder(presence_bits)]`, after its
+// position among the struct's fields.
+fn presence_bit(f: &BuilderField) -> u64 {
+    1 << f.index
+}
 
-    let builder_name = Ident::new(&format!("{}Builder", &input.ident), Span::call_site());
+// With `#[builder(build_fn(error = "..."))]` the error type is made from the
+// message through `From<&'static str>`.
+fn missing_error(ident: &Ident, attrs: &StructAttrs) -> TokenStream {
+    let message = quote! {concat!("field `", stringify!(#ident), "` is not set")};
+    match &attrs.build_error {
+        Some(error) => quote! {<#error as ::std::convert::From<&'static str>>::from(#message)},
+        None => quote! {anyhow::Error::msg(#message)},
+    }
+}
+
+// The value `build` gives the field whether or not its setter was called, or
+// None if the field is required. Values are moved out of the builder, so field
+// types need not implement Clone.
+fn fallback(f: &BuilderField, attrs: &StructAttrs) -> Option<TokenStream> {
+    let ident = f.field.ident.as_ref()?;
+    let stored = quote! {self.#ident.take()};
+    fallback_from(
+        f,
+        attrs,
+        &stored,
+        &quote! {::std::mem::take(&mut self.#ident)},
+    )
+}
+
+// Like `fallback`, but cloning the value out of the builder.
+fn cloned_fallback(f: &BuilderField, attrs: &StructAttrs) -> Option<TokenStream> {
+    let ident = f.field.ident.as_ref()?;
+    let stored = quote! {self.#ident.clone()};
+    fallback_from(f, attrs, &stored, &stored)
+}
+
+// `stored` reads the Option a field is kept in, and `list` the Vec of a field
+// collected with `each`.
+fn fallback_from(
+    f: &BuilderField,
+    attrs: &StructAttrs,
+    stored: &TokenStream,
+    list: &TokenStream,
+) -> Option<TokenStream> {
+    let is_option = unwrap_generic(&f.field.ty, "Option").is_some();
+    match (&f.default, &attrs.field_default) {
+        _ if f.skip.is_some() => f.skip.as_ref().map(|value| quote! {#value}),
+        _ if f.each.is_some() => Some(list.clone()),
+        (Some(default), _) if is_option => Some(quote! {#stored.or_else(|| #default)}),
+        _ if is_option => Some(stored.clone()),
+        (Some(default), _) => Some(quote! {#stored.unwrap_or_else(|| #default)}),
+        (None, Some(default)) => Some(quote! {#stored.unwrap_or_else(#default)}),
+        (None, None) => None,
+    }
+}
+
+// The trait behind one of the standard derives. A derive macro's name does not
+// always resolve to its trait, as with Debug, which the prelude only has as a
+// derive.
+fn std_trait(name: &str) -> Option<TokenStream> {
+    match name {
+        "Clone" => Some(quote!(::std::clone::Clone)),
+        "Debug" => Some(quote!(::std::fmt::Debug)),
+        "PartialEq" => Some(quote!(::std::cmp::PartialEq)),
+        "Eq" => Some(quote!(::std::cmp::Eq)),
+        "PartialOrd" => Some(quote!(::std::cmp::PartialOrd)),
+        "Ord" => Some(quote!(::std::cmp::Ord)),
+        "Hash" => Some(quote!(::std::hash::Hash)),
+        _ => None,
+    }
+}
+
+// A derive requested with `#[builder(derive(...))]` fails on any field type
+// lacking the trait, with an error pointing at the derive. With
+// `#[builder(check_derives)]`, asserting the trait for each field type up
+// front also points at the field and says why the trait is needed. The
+// derive's own error still follows, so the check is opt-in. Other derives
+// than the standard ones, and generic structs, whose field types cannot be
+// named outside the builder's impls, are left to the derive's own error.
+fn derive_checks(input: &DeriveInput, fields: &[BuilderField], derives: &[Path]) -> TokenStream {
+    if !input.generics.params.is_empty() {
+        return TokenStream::new();
+    }
+    derives
+        .iter()
+        .filter_map(|path| {
+            let name = path.segments.last()?.ident.to_string();
+            let path = std_trait(&name)?;
+            let message = format!("`{{Self}}` must implement `{}`", name);
+            let note = format!(
+                "#[builder(derive({0}))] requires every field type to implement `{0}`; \
+                 implement it for `{{Self}}` or stop deriving it for the builder",
+                name
+            );
+            let asserts = fields.iter().filter(|f| f.skip.is_none()).map(|f| {
+                let ty = &f.field.ty;
+                quote_spanned! {ty.span()=> assert::<#ty>();}
+            });
+            Some(quote! {
+                const _: fn() = || {
+                    #[diagnostic::on_unimplemented(message = #message, note = #note)]
+                    trait FieldImplements {}
+                    impl<T: #path> FieldImplements for T {}
+                    fn assert<T: FieldImplements>() {}
+                    #(#asserts)*
+                };
+            })
+        })
+        .collect()
+}
+
+fn impl_struct(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream> {
+    let attrs = StructAttrs::parse(&input.attrs)?;
+    let fields = data
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| BuilderField::parse(field, index))
+        .collect::<syn::Result<Vec<BuilderField>>>()?;
+
+    if attrs.presence_bits && fields.len() > 64 {
+        return Err(syn::Error::new_spanned(
+            &input.ident,
+            "#[builder(presence_bits)] supports at most 64 fields",
+        ));
+    }
+
+    // Every setter also comes with a `with_` variant, and neither may shadow
+    // the builder's own methods.
+    let build_fn = attrs
+        .build_fn
+        .clone()
+        .unwrap_or_else(|| Ident::new("build", Span::call_site()));
+    let build_error = match &attrs.build_error {
+        Some(error) => quote!(#error),
+        None => quote!(anyhow::Error),
+    };
+    let build_name = build_fn.to_string();
+    let mut own_methods = vec!["try_build", "build_or_default", "reset", "apply"];
+    if attrs.presence_bits {
+        own_methods.push("is_set");
+    }
+    if own_methods.contains(&build_name.as_str()) {
+        return Err(syn::Error::new_spanned(
+            &build_fn,
+            format!(
+                "build_fn `{}` clashes with the builder's own method",
+                build_fn
+            ),
+        ));
+    }
+    own_methods.push(&build_name);
+    let mut method_names: Vec<String> = own_methods.iter().map(|m| m.to_string()).collect();
+    for name in fields.iter().flat_map(BuilderField::setter_names) {
+        for method in [name.to_string(), format!("with_{}", name)] {
+            if method_names.contains(&method) {
+                let message = if own_methods.contains(&method.as_str()) {
+                    format!("setter `{}` clashes with the builder's own method", method)
+                } else {
+                    format!("setter `{}` is already defined by another field", method)
+                };
+                return Err(syn::Error::new_spanned(name, message));
+            }
+            method_names.push(method);
+        }
+    }
+
+    let vis = &input.vis;
+
+    let setters = extract_setter(&fields, vis, &attrs);
+
+    let mut builder_fields = extract_fields(&fields, &attrs);
+
+    let mut default_fields = extract_default_fields(&fields);
+
+    let mut edit_fields = extract_edit_fields(&fields);
+
+    let required_checks = extract_required_checks(&fields, &attrs);
+
+    let build_fields = extract_builder_fields(&fields, &attrs);
+
+    let try_build_fields = extract_try_build_fields(&fields, &attrs);
+
+    let clone_bounds = extract_clone_bounds(&fields);
+
+    let or_default_fields = extract_or_default_fields(&fields, &attrs);
+
+    let or_default_bounds = extract_or_default_bounds(&fields, &attrs);
+
+    let builder_name = match &attrs.name {
+        Some(name) => name.clone(),
+        None => Ident::new(&format!("{}Builder", &input.ident), Span::call_site()),
+    };
     let struct_name = &input.ident;
+    let generics = &input.generics;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let default_impl = if attrs.derive_default {
+        // Default cannot fail, while a validator may reject the defaults, so
+        // the two are not combined rather than panicking in `default`.
+        if let Some(validate) = &attrs.validate {
+            return Err(syn::Error::new_spanned(
+                validate,
+                "#[builder(validate)] cannot be combined with #[builder(derive_default)]",
+            ));
+        }
+        if let Some(f) = fields.iter().find(|f| fallback(f, &attrs).is_none()) {
+            return Err(syn::Error::new_spanned(
+                f.field,
+                "#[builder(derive_default)] requires every field to have a default",
+            ));
+        }
+        // Built through `build`, which unlike `try_build` needs no Clone.
+        Some(quote! {
+            impl #impl_generics ::std::default::Default
+                for #struct_name #ty_generics #where_clause
+            {
+                fn default() -> Self {
+                    match #builder_name::default().#build_fn() {
+                        Ok(built) => built,
+                        Err(_) => unreachable!("every field has a default"),
+                    }
+                }
+            }
+        })
+    } else {
+        None
+    };
+
+    let derives = if attrs.derives.is_empty() {
+        None
+    } else {
+        let traits = &attrs.derives;
+        Some(quote! {#[derive(#(#traits),*)]})
+    };
+    let derive_checks = if attrs.check_derives {
+        derive_checks(input, &fields, &attrs.derives)
+    } else {
+        TokenStream::new()
+    };
+    let test_derives = if attrs.test_derives.is_empty() {
+        None
+    } else {
+        let traits = &attrs.test_derives;
+        Some(quote! {#[cfg_attr(test, derive(#(#traits),*))]})
+    };
+    let builder_doc = format!("Builder for [`{}`].", struct_name);
+    let builder_fn_doc = format!("Creates a [`{}`] with no field set.", builder_name);
+    let try_build_doc = format!(
+        "Like [`{}`](Self::{0}), but clones the values set so far, leaving the builder as \
+         it is, and returns None if a required field has not been set{}.",
+        build_fn,
+        if attrs.validate.is_some() {
+            " or the validator rejects the struct"
+        } else {
+            ""
+        }
+    );
+    let or_default_doc = format!(
+        "Like [`{}`](Self::{0}), but clones the values set so far, leaving the builder as \
+         it is, and gives every required field that has not been set its type's default \
+         value instead of failing{}.",
+        build_fn,
+        if attrs.validate.is_some() {
+            ".\n\n# Errors\n\nReturns the error of the validator, converted with `From`, if \
+             it rejects the struct, which a struct made of defaults can still be"
+        } else {
+            ""
+        }
+    );
+    let edit_doc = format!(
+        "Creates a [`{}`] with every field set from `existing`.",
+        builder_name
+    );
+    // The trait is declared by the user, since a proc-macro crate cannot
+    // export one, and is expected to have exactly this shape.
+    let trait_impl = attrs.builder_trait.as_ref().map(|path| {
+        quote! {
+            impl #impl_generics #path for #struct_name #ty_generics #where_clause {
+                type Builder = #builder_name #ty_generics;
+
+                fn builder() -> Self::Builder {
+                    #builder_name::default()
+                }
+            }
+        }
+    });
+    // Skipped fields are not stored, so a type parameter or lifetime only they
+    // use, as in `#[builder(skip)] marker: PhantomData<T>`, would go unused in
+    // the builder. A marker field uses it instead, through a function pointer
+    // so that it adds no auto trait or drop check requirements, and through
+    // PhantomData so that an unsized parameter is allowed.
+    let stored_types: Vec<TokenStream> = fields
+        .iter()
+        .filter(|f| f.skip.is_none())
+        .map(|f| f.field.ty.to_token_stream())
+        .collect();
+    let unused: Vec<TokenStream> = generics
+        .params
+        .iter()
+        .filter_map(|param| match param {
+            GenericParam::Type(t) => {
+                let ident = &t.ident;
+                let used = stored_types
+                    .iter()
+                    .any(|ty| mentions(ty.clone(), ident, false));
+                (!used).then(|| quote!(::std::marker::PhantomData<#ident>))
+            }
+            GenericParam::Lifetime(l) => {
+                let lifetime = &l.lifetime;
+                let used = stored_types
+                    .iter()
+                    .any(|ty| mentions(ty.clone(), &lifetime.ident, true));
+                (!used).then(|| quote!(&#lifetime ()))
+            }
+            // Const parameters may go unused.
+            GenericParam::Const(_) => None,
+        })
+        .collect();
+    if !unused.is_empty() {
+        builder_fields.push(quote! {__marker: ::std::marker::PhantomData<fn() -> (#(#unused,)*)>});
+        default_fields.push(quote! {__marker: ::std::marker::PhantomData});
+        edit_fields.push(quote! {__marker: ::std::marker::PhantomData});
+    }
+    // With `#[builder(presence_bits)]`, bit `i` of `__presence` is set once the
+    // struct's field `i` has been given a value, and cleared again when a
+    // build moves the values out.
+    let (presence_clear, presence_method) = if attrs.presence_bits {
+        let all = fields
+            .iter()
+            .filter(|f| f.skip.is_none())
+            .map(presence_bit)
+            .fold(0, |all, bit| all | bit);
+        builder_fields.push(quote! {__presence: u64});
+        default_fields.push(quote! {__presence: 0});
+        edit_fields.push(quote! {__presence: #all});
+        let method = quote! {
+            /// Whether the struct's field at `field_index`, counting from 0 in
+            /// declaration order, has been given a value since the builder was
+            /// created, reset or last built.
+            #[must_use]
+            #vis fn is_set(&self, field_index: usize) -> bool {
+                field_index < 64 && self.__presence & (1 << field_index) != 0
+            }
+        };
+        (Some(quote! {self.__presence = 0;}), Some(method))
+    } else {
+        (None, None)
+    };
+    // Skipped fields keep the value they are always built with, so a random
+    // struct is one the builder could have produced, though not necessarily
+    // one the validator accepts.
+    let random_impl = if attrs.test_random {
+        let random_doc = if attrs.validate.is_some() {
+            "Creates a struct with every field set to a random value. The validator is not \
+             run, so the struct may be one it rejects."
+        } else {
+            "Creates a struct with every field set to a random value."
+        };
+        let values = fields.iter().map(|f| {
+            let ident = &f.field.ident;
+            match &f.skip {
+                Some(skip) => quote! {#ident: #skip},
+                None => quote! {#ident: ::rand::Rng::gen(rng)},
+            }
+        });
+        let bounds = fields.iter().filter(|f| f.skip.is_none()).map(|f| {
+            let ty = &f.field.ty;
+            quote! {::rand::distributions::Standard: ::rand::distributions::Distribution<#ty>}
+        });
+        Some(quote! {
+            #[cfg(test)]
+            impl #impl_generics #struct_name #ty_generics #where_clause {
+                #[doc = #random_doc]
+                #vis fn random(rng: &mut impl ::rand::Rng) -> Self
+                where
+                    #(#bounds),*
+                {
+                    #struct_name {
+                        #(#values),*
+                    }
+                }
+            }
+        })
+    } else {
+        None
+    };
+    let after_build = attrs.after_build.iter();
+    let try_after_build = attrs.after_build.iter();
+    let or_default_after_build = attrs.after_build.iter();
+    // The validator sees the struct as it would be returned, after
+    // `after_build`. With one, `build_or_default` returns a Result as well,
+    // since a struct made of defaults can still be rejected.
+    let (or_default_output, or_default_validate, or_default_must_use) = match &attrs.validate {
+        Some(path) => (
+            quote! {Result<#struct_name #ty_generics, #build_error>},
+            quote! {#path(&built)?; Ok(built)},
+            None,
+        ),
+        None => (
+            quote! {#struct_name #ty_generics},
+            quote! {built},
+            Some(quote! {#[must_use]}),
+        ),
+    };
+    let (validate, try_validate, validate_doc) = match &attrs.validate {
+        Some(path) => (
+            Some(quote! {#path(&built)?;}),
+            Some(quote! {#path(&built).ok()?;}),
+            Some(quote! {
+                ///
+                /// Also returns the error of the validator, converted with
+                /// `From`, if it rejects the struct. The values have been
+                /// moved out of the builder by then.
+            }),
+        ),
+        None => (None, None, None),
+    };
+    // Default is implemented by hand because deriving it would require every
+    // type parameter to implement Default, even though all fields start empty.
+    // The output is meant to be clean under `clippy::pedantic`; the lints
+    // allowed below are ones the generated code cannot avoid for every input.
     Ok(quote! {
-         #[derive(Default, Debug, Clone)]
-         pub struct #builder_name {
-             #(#fields),*
-         }
-        impl #builder_name {
-            fn build(&mut self) -> Result<#struct_name, anyhow::Error>{
-                Ok(#struct_name{
+        #derive_checks
+        #derives
+        #test_derives
+        #[doc = #builder_doc]
+        #vis struct #builder_name #generics #where_clause {
+            #(#builder_fields),*
+        }
+        #[allow(clippy::derivable_impls, clippy::elidable_lifetime_names)]
+        impl #impl_generics ::std::default::Default for #builder_name #ty_generics #where_clause {
+            fn default() -> Self {
+                #builder_name {
+                    #(#default_fields),*
+                }
+            }
+        }
+        impl #impl_generics #builder_name #ty_generics #where_clause {
+            /// Moves the values set so far into a new struct, failing if a
+            /// required field has not been set.
+            ///
+            /// # Errors
+            ///
+            /// Returns an error naming the first required field that has not
+            /// been set, in which case the builder is left untouched.
+            #validate_doc
+            #vis fn #build_fn(&mut self) -> Result<#struct_name #ty_generics, #build_error>{
+                #(#required_checks)*
+                #[allow(unused_mut, clippy::unnecessary_lazy_evaluations)]
+                let mut built = #struct_name{
                     #(#build_fields),*
-                })
+                };
+                #presence_clear
+                #(#after_build(&mut built);)*
+                #validate
+                Ok(built)
+            }
+            #[doc = #try_build_doc]
+            #[must_use]
+            #vis fn try_build(&self) -> Option<#struct_name #ty_generics>
+            where
+                #(#clone_bounds),*
+            {
+                #[allow(
+                    unused_mut,
+                    clippy::unnecessary_lazy_evaluations,
+                    clippy::clone_on_copy
+                )]
+                let mut built = #struct_name{
+                    #(#try_build_fields),*
+                };
+                #(#try_after_build(&mut built);)*
+                #try_validate
+                Some(built)
             }
+            #[doc = #or_default_doc]
+            #or_default_must_use
+            #vis fn build_or_default(&self) -> #or_default_output
+            where
+                #(#clone_bounds,)*
+                #(#or_default_bounds),*
+            {
+                #[allow(
+                    unused_mut,
+                    clippy::unnecessary_lazy_evaluations,
+                    clippy::clone_on_copy
+                )]
+                let mut built = #struct_name{
+                    #(#or_default_fields),*
+                };
+                #(#or_default_after_build(&mut built);)*
+                #or_default_validate
+            }
+            /// Calls `f` on the builder, so that setters can be chosen at
+            /// run time without breaking up a chain of calls.
+            #vis fn apply(&mut self, f: impl FnOnce(&mut Self) -> &mut Self) -> &mut Self {
+                f(self)
+            }
+            /// Clears every field, as if the builder had just been created.
+            #vis fn reset(&mut self) -> &mut Self {
+                *self = Self::default();
+                self
+            }
+            #presence_method
             #(#setters)*
         }
-        impl #struct_name {
-            fn builder() -> #builder_name {
+        impl #impl_generics ::std::convert::TryFrom<#builder_name #ty_generics>
+            for #struct_name #ty_generics #where_clause
+        {
+            type Error = #build_error;
+
+            fn try_from(mut builder: #builder_name #ty_generics) -> Result<Self, Self::Error> {
+                builder.#build_fn()
+            }
+        }
+        impl #impl_generics #struct_name #ty_generics #where_clause {
+            #[doc = #builder_fn_doc]
+            #[must_use]
+            #vis fn builder() -> #builder_name #ty_generics {
                 #builder_name::default()
             }
+            #[doc = #edit_doc]
+            #[must_use]
+            #vis fn edit(existing: Self) -> #builder_name #ty_generics {
+                #builder_name {
+                    #(#edit_fields),*
+                }
+            }
         }
+        #trait_impl
+        #default_impl
+        #random_impl
     })
 }
@@ -1,12 +1,15 @@
 extern crate proc_macro;
 
+mod attrs;
 mod builder;
 
 use proc_macro::TokenStream;
 use syn::{parse_macro_input, DeriveInput};
 
-#[proc_macro_derive(Builder)]
+#[proc_macro_derive(Builder, attributes(builder))]
 pub fn derive(input: TokenStream) -> TokenStream {
     let i = parse_macro_input!(input as DeriveInput);
-    builder::build(&i).unwrap().into()
+    builder::build(&i)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
 }
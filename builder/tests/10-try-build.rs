@@ -0,0 +1,42 @@
+// Generate a `try_build` method alongside `build`.
+//
+// Unlike `build`, this borrows the builder immutably and returns an Option:
+// Some when every required field has been set, None otherwise. Optional
+// fields may be left unset without making the result None.
+//
+//     impl CommandBuilder {
+//         fn try_build(&self) -> Option<Command> {
+//             ...
+//         }
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned());
+    assert!(builder.try_build().is_none());
+
+    builder.args(vec!["build".to_owned()]);
+    let command = builder.try_build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert!(command.current_dir.is_none());
+
+    // The builder is left untouched and can be probed again.
+    assert!(builder.try_build().is_some());
+
+    // Probing twice gives the same struct both times, and `build` still finds
+    // every value afterwards.
+    let again = builder.try_build().unwrap();
+    assert_eq!(again.executable, command.executable);
+    assert_eq!(again.args, command.args);
+    assert_eq!(builder.build().unwrap().args, vec!["build"]);
+}
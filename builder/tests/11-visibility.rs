@@ -0,0 +1,41 @@
+// The builder struct and every generated method take the visibility of the
+// input struct, so a `pub` struct's builder is usable from outside the module
+// that defines it, while a private struct keeps its builder private.
+
+mod shapes {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct Point {
+        pub x: i32,
+        pub y: i32,
+    }
+
+    #[derive(Builder)]
+    pub(crate) struct Size {
+        pub width: u32,
+        pub height: u32,
+    }
+
+    #[derive(Builder)]
+    struct Hidden {
+        value: u8,
+    }
+
+    pub fn hidden_value() -> u8 {
+        Hidden::builder().value(7).build().unwrap().value
+    }
+}
+
+use shapes::{Point, PointBuilder, Size};
+
+fn main() {
+    let mut builder: PointBuilder = Point::builder();
+    let point = builder.x(1).y(2).build().unwrap();
+    assert_eq!((point.x, point.y), (1, 2));
+
+    let size = Size::builder().width(3).height(4).try_build().unwrap();
+    assert_eq!((size.width, size.height), (3, 4));
+
+    assert_eq!(shapes::hidden_value(), 7);
+}
@@ -0,0 +1,38 @@
+// A struct-level `#[builder(field_default)]` makes every unset field fall back
+// to `Default::default()` when building, so no field is required. Every
+// field type must implement Default.
+//
+// The fallback can also be an explicit function path, which is called once for
+// each unset field: `#[builder(field_default = "Default::default")]`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(field_default)]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    retries: u32,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(field_default = "Default::default")]
+pub struct Limits {
+    max: u64,
+}
+
+fn main() {
+    let command = Command::builder().build().unwrap();
+    assert_eq!(command.executable, "");
+    assert!(command.args.is_empty());
+    assert_eq!(command.retries, 0);
+    assert!(command.current_dir.is_none());
+
+    let command = Command::builder().retries(3).try_build().unwrap();
+    assert_eq!(command.retries, 3);
+    assert_eq!(command.executable, "");
+
+    assert_eq!(Limits::builder().build().unwrap().max, 0);
+    assert_eq!(Limits::builder().max(9).build().unwrap().max, 9);
+}
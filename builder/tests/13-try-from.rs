@@ -0,0 +1,36 @@
+// The target struct implements `TryFrom<CommandBuilder>`, sharing the
+// required-field checks of `build`. A builder with an unset required field
+// converts into the same error that `build` would return.
+//
+// The builder only derives Default unless more traits are requested with
+// `#[builder(derive(...))]`; Clone is used here to convert a copy of it.
+
+use derive_builder::Builder;
+use std::convert::{TryFrom, TryInto};
+
+#[derive(Builder)]
+#[builder(derive(Clone))]
+pub struct Command {
+    executable: String,
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn convert() -> Result<Command, anyhow::Error> {
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned()).args(vec![]);
+    let command: Command = builder.try_into()?;
+    Ok(command)
+}
+
+fn main() {
+    let command = convert().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert!(command.current_dir.is_none());
+
+    let mut builder = Command::builder();
+    builder.executable("cargo".to_owned());
+    let err = Command::try_from(builder.clone()).err().unwrap();
+    assert_eq!(err.to_string(), "field `args` is not set");
+    assert_eq!(builder.build().err().unwrap().to_string(), err.to_string());
+}
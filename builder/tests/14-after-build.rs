@@ -0,0 +1,35 @@
+// `#[builder(after_build = "path")]` names a function that receives the
+// freshly built struct by `&mut` just before it is returned, which is the
+// place to fill in computed fields or establish invariants.
+//
+//     fn finalize(person: &mut Person) { ... }
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(after_build = "finalize")]
+pub struct Person {
+    first: String,
+    last: String,
+    full_name: Option<String>,
+}
+
+fn finalize(person: &mut Person) {
+    person.full_name = Some(format!("{} {}", person.first, person.last));
+}
+
+fn main() {
+    let person = Person::builder()
+        .first("Ada".to_owned())
+        .last("Lovelace".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(person.full_name.as_deref(), Some("Ada Lovelace"));
+
+    let person = Person::builder()
+        .first("Alan".to_owned())
+        .last("Turing".to_owned())
+        .try_build()
+        .unwrap();
+    assert_eq!(person.full_name.as_deref(), Some("Alan Turing"));
+}
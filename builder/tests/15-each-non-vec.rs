@@ -0,0 +1,14 @@
+// The `each` setter pushes one element at a time, so it only makes sense on a
+// Vec field. Anywhere else it is rejected with an error pointing at the
+// attribute, instead of generating code that fails to typecheck.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: String,
+}
+
+fn main() {}
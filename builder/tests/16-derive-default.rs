@@ -0,0 +1,34 @@
+// With `#[builder(derive_default)]` the struct itself also gets a Default
+// impl, producing the same value as building with no setters called. Every
+// field needs something to fall back to, here through `field_default`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(field_default, derive_default)]
+pub struct Config {
+    name: String,
+    retries: u32,
+    tags: Vec<String>,
+    parent: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(derive_default)]
+pub struct Flags {
+    #[builder(each = "flag")]
+    flags: Vec<String>,
+    level: Option<u8>,
+}
+
+fn main() {
+    let config = Config::default();
+    assert_eq!(config.name, "");
+    assert_eq!(config.retries, 0);
+    assert!(config.tags.is_empty());
+    assert!(config.parent.is_none());
+
+    let flags = Flags::default();
+    assert!(flags.flags.is_empty());
+    assert!(flags.level.is_none());
+}
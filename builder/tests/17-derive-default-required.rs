@@ -0,0 +1,13 @@
+// A required field without any default makes `derive_default` impossible, so
+// the derive points at that field.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(derive_default)]
+pub struct Config {
+    name: String,
+    parent: Option<String>,
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+// A field attribute `#[builder(default = "expr")]` gives the value to use when
+// the setter was never called. Such a field is not required, so leaving it
+// unset does not make build fail. The expression is evaluated on each build
+// and has the field's declared type, so on an Option field it is an Option.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    host: String,
+    #[builder(default = "8080")]
+    port: u16,
+    #[builder(default = "vec![\"http\".to_owned()]")]
+    protocols: Vec<String>,
+    #[builder(default = "Some(30)")]
+    timeout: Option<u32>,
+}
+
+fn main() {
+    let server = Server::builder().host("localhost".to_owned()).build().unwrap();
+    assert_eq!(server.port, 8080);
+    assert_eq!(server.protocols, ["http"]);
+    assert_eq!(server.timeout, Some(30));
+
+    let server = Server::builder()
+        .host("localhost".to_owned())
+        .port(443)
+        .timeout(5)
+        .try_build()
+        .unwrap();
+    assert_eq!(server.port, 443);
+    assert_eq!(server.timeout, Some(5));
+
+    let err = Server::builder().port(1).build().err().unwrap();
+    assert_eq!(err.to_string(), "field `host` is not set");
+}
@@ -0,0 +1,12 @@
+// The default must parse as a Rust expression; otherwise the error points at
+// the string.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Server {
+    #[builder(default = "8080 +")]
+    port: u16,
+}
+
+fn main() {}
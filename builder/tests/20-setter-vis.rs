@@ -0,0 +1,29 @@
+// A field's setters can be given their own visibility with
+// `#[builder(setter(vis = "..."))]`. The string must parse as a Rust
+// visibility. Setters on other fields keep the visibility of the struct.
+
+mod shape {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct Rect {
+        pub width: u32,
+        pub height: u32,
+        #[builder(setter(vis = "pub(crate)"))]
+        pub id: u64,
+    }
+}
+
+use shape::Rect;
+
+fn main() {
+    let rect = Rect::builder()
+        .width(3)
+        .height(4)
+        .id(7)
+        .build()
+        .unwrap();
+
+    assert_eq!(rect.width * rect.height, 12);
+    assert_eq!(rect.id, 7);
+}
@@ -0,0 +1,20 @@
+// A setter restricted with `#[builder(setter(vis = "pub(self)"))]` is private
+// to the module declaring the struct, even though the struct and its other
+// setters are public.
+
+mod shape {
+    use derive_builder::Builder;
+
+    #[derive(Builder)]
+    pub struct Rect {
+        pub width: u32,
+        #[builder(setter(vis = "pub(self)"))]
+        pub id: u64,
+    }
+}
+
+use shape::Rect;
+
+fn main() {
+    let _ = Rect::builder().width(3).id(7).build();
+}
@@ -0,0 +1,47 @@
+// The builder derives nothing but Default, and `build` moves each value out of
+// the builder rather than cloning it. Field types therefore need not implement
+// Clone or Debug.
+//
+// Debug and Clone can still be derived for the builder on request, which
+// requires every field type to implement them:
+//
+//     #[builder(derive(Debug, Clone))]
+
+use derive_builder::Builder;
+
+pub struct Handle {
+    fd: i32,
+}
+
+#[derive(Builder)]
+pub struct Connection {
+    handle: Handle,
+    #[builder(each = "peer")]
+    peers: Vec<Handle>,
+    backup: Option<Handle>,
+}
+
+#[derive(Builder)]
+#[builder(derive(Debug, Clone))]
+pub struct Endpoint {
+    host: String,
+    port: u16,
+}
+
+fn main() {
+    let mut builder = Connection::builder();
+    builder.peer(Handle { fd: 4 });
+    assert!(builder.build().is_err());
+
+    let conn = builder.handle(Handle { fd: 3 }).build().unwrap();
+    assert_eq!(conn.handle.fd, 3);
+    assert_eq!(conn.peers.len(), 1);
+    assert!(conn.backup.is_none());
+
+    let mut builder = Endpoint::builder();
+    builder.host("localhost".to_owned()).port(80);
+    let copy = builder.clone();
+    assert!(format!("{:?}", copy).starts_with("EndpointBuilder"));
+    let endpoint = builder.build().unwrap();
+    assert_eq!((endpoint.host.as_str(), endpoint.port), ("localhost", 80));
+}
@@ -0,0 +1,35 @@
+// `#[builder(setter = "name")]` gives a field's setter a different name from
+// the field itself. The field and the builder's storage for it keep their
+// real name, and an `each` setter is still named separately.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Token {
+    #[builder(setter = "kind")]
+    type_: String,
+    #[builder(each = "flag", setter = "all_flags")]
+    flags: Vec<String>,
+    #[builder(setter = "offset")]
+    start: Option<usize>,
+}
+
+fn main() {
+    let token = Token::builder()
+        .kind("ident".to_owned())
+        .flag("raw".to_owned())
+        .offset(4)
+        .build()
+        .unwrap();
+    assert_eq!(token.type_, "ident");
+    assert_eq!(token.flags, vec!["raw"]);
+    assert_eq!(token.start, Some(4));
+
+    let token = Token::builder()
+        .kind("punct".to_owned())
+        .all_flags(vec!["joint".to_owned(), "alone".to_owned()])
+        .build()
+        .unwrap();
+    assert_eq!(token.flags.len(), 2);
+    assert!(token.start.is_none());
+}
@@ -0,0 +1,13 @@
+// Two fields cannot end up with setters of the same name. The error points at
+// the second name.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Token {
+    kind: String,
+    #[builder(setter = "kind")]
+    type_: String,
+}
+
+fn main() {}
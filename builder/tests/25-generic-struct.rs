@@ -0,0 +1,46 @@
+// Generic structs get a builder with the same generic parameters and where
+// clause. Values are moved from the builder into the struct, so no bound
+// beyond those the struct declares is needed: a type parameter does not have
+// to implement Clone or Default.
+//
+// A builder that opts into `#[builder(derive(Clone))]` is only Clone when its
+// type parameters are, which is the bound derive(Clone) adds to its own impl.
+
+use derive_builder::Builder;
+use std::fmt::Display;
+
+pub struct Socket(u16);
+
+#[derive(Builder)]
+pub struct Pool<T> {
+    items: Vec<T>,
+    spare: Option<T>,
+    name: String,
+}
+
+#[derive(Builder)]
+#[builder(derive(Clone))]
+pub struct Labeled<'a, L>
+where
+    L: Display,
+{
+    label: L,
+    text: &'a str,
+}
+
+fn main() {
+    let pool = Pool::builder()
+        .items(vec![Socket(80), Socket(443)])
+        .name("web".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(pool.items[1].0, 443);
+    assert!(pool.spare.is_none());
+    assert_eq!(pool.name, "web");
+
+    let mut builder = Labeled::builder();
+    builder.label(7).text("seven");
+    let labeled = builder.clone().build().unwrap();
+    assert_eq!(format!("{}: {}", labeled.label, labeled.text), "7: seven");
+    assert!(builder.try_build().is_some());
+}
@@ -0,0 +1,27 @@
+// Only the outer `Option` of a field is treated as "maybe set". For an
+// `Option<Option<T>>` field the setter takes `Option<T>`, so leaving the setter
+// uncalled builds `None` while calling it with `None` builds `Some(None)`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Patch {
+    id: u32,
+    nickname: Option<Option<String>>,
+}
+
+fn main() {
+    let untouched = Patch::builder().id(1).build().unwrap();
+    assert_eq!(untouched.nickname, None);
+
+    let cleared = Patch::builder().id(1).nickname(None).build().unwrap();
+    assert_eq!(cleared.nickname, Some(None));
+
+    let renamed = Patch::builder()
+        .id(1)
+        .nickname(Some("bee".to_owned()))
+        .build()
+        .unwrap();
+    assert_eq!(renamed.nickname, Some(Some("bee".to_owned())));
+    assert_eq!(renamed.id, 1);
+}
@@ -0,0 +1,13 @@
+// Problems with the input are reported as compile errors pointing at the
+// offending tokens, never as a panic inside the macro. An unknown option in a
+// struct-level `#[builder(...)]` is one such problem.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(field_defualt)]
+pub struct Command {
+    executable: String,
+}
+
+fn main() {}
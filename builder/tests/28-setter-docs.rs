@@ -0,0 +1,36 @@
+// Doc comments on a field are copied onto the setters generated for it, so
+// they show up in rustdoc and IDE hovers for the builder. Other attributes on
+// the field, including `#[builder(...)]` itself, stay on the field.
+//
+// The builder and its remaining methods come with short docs of their own, so
+// a documented struct yields a fully documented builder. This test denies
+// missing docs to check exactly that.
+
+#![deny(missing_docs)]
+//! Documented builder.
+
+use derive_builder::Builder;
+
+/// A command to run.
+#[derive(Builder)]
+pub struct Command {
+    /// Program to run.
+    executable: String,
+    /// Arguments passed to the program,
+    /// one at a time.
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[doc = "Working directory, if not the current one."]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert!(command.current_dir.is_none());
+}
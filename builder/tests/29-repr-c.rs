@@ -0,0 +1,33 @@
+// The struct is constructed with named fields, so its layout has no bearing on
+// the builder: a `#[repr(C)]` struct keeps its declared field order, and the
+// values end up in the right fields whatever order the setters are called in.
+
+use derive_builder::Builder;
+use std::mem::{offset_of, size_of};
+
+#[derive(Builder)]
+#[repr(C)]
+pub struct Header {
+    tag: u8,
+    length: u32,
+    flags: u16,
+    checksum: Option<u16>,
+}
+
+fn main() {
+    let header = Header::builder()
+        .flags(0x8000)
+        .length(512)
+        .tag(7)
+        .build()
+        .unwrap();
+    assert_eq!(header.tag, 7);
+    assert_eq!(header.length, 512);
+    assert_eq!(header.flags, 0x8000);
+    assert!(header.checksum.is_none());
+
+    assert_eq!(offset_of!(Header, tag), 0);
+    assert_eq!(offset_of!(Header, length), 4);
+    assert_eq!(offset_of!(Header, flags), 8);
+    assert_eq!(size_of::<Header>(), 16);
+}
@@ -0,0 +1,40 @@
+// Every setter has a by-value counterpart named with a `with_` prefix, which
+// takes the builder by value and returns it. This lets a builder be set up and
+// built in a single expression, without a `let mut` binding.
+//
+//     impl CommandBuilder {
+//         fn with_executable(mut self, executable: String) -> Self {
+//             ...
+//         }
+//     }
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(setter = "dir")]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = CommandBuilder::default()
+        .with_executable("cargo".to_owned())
+        .with_arg("build".to_owned())
+        .with_arg("--release".to_owned())
+        .with_dir("..".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let command = Command::builder()
+        .with_args(vec!["test".to_owned()])
+        .with_executable("cargo".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.args, vec!["test"]);
+}
@@ -0,0 +1,18 @@
+// Traits derived for the builder with `#[builder(derive(...))]` must be
+// implemented by every field type. With `#[builder(check_derives)]`, a field
+// type lacking one gets an extra error pointing at that field and naming the
+// attribute asking for the trait, ahead of the derive's own error, which
+// only points at the derive. Without the flag only the latter is reported.
+
+use derive_builder::Builder;
+
+pub struct Handle(i32);
+
+#[derive(Builder)]
+#[builder(derive(Clone), check_derives)]
+pub struct Connection {
+    name: String,
+    handle: Handle,
+}
+
+fn main() {}
@@ -0,0 +1,32 @@
+// `Struct::edit(existing)` starts a builder from an existing value, with every
+// field already set. Setters then override individual fields, and `build`
+// returns the modified struct. The value is moved into the builder, so the
+// struct does not need to implement Clone.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .current_dir("..".to_owned())
+        .build()
+        .unwrap();
+
+    let edited = Command::edit(command)
+        .executable("cross".to_owned())
+        .arg("--release".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(edited.executable, "cross");
+    assert_eq!(edited.args, vec!["build", "--release"]);
+    assert_eq!(edited.current_dir.as_deref(), Some(".."));
+}
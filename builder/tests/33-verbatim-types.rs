@@ -0,0 +1,41 @@
+// Only `Option` and fields collected with `each` are unwrapped. Any other
+// field type, generic containers included, is used verbatim for both the
+// setter's parameter and the builder's storage.
+//
+// Coercing each setter to a function pointer pins down its exact signature.
+
+use derive_builder::Builder;
+use std::collections::HashMap;
+
+#[derive(Builder)]
+pub struct Registry {
+    counts: HashMap<String, u32>,
+    last: Result<u8, String>,
+    hook: Box<dyn Fn(u32) -> u32>,
+    nested: Vec<Option<HashMap<u8, Vec<u8>>>>,
+}
+
+fn main() {
+    let _: fn(&mut RegistryBuilder, HashMap<String, u32>) -> &mut RegistryBuilder =
+        RegistryBuilder::counts;
+    let _: fn(&mut RegistryBuilder, Result<u8, String>) -> &mut RegistryBuilder =
+        RegistryBuilder::last;
+    let _: fn(&mut RegistryBuilder, Box<dyn Fn(u32) -> u32>) -> &mut RegistryBuilder =
+        RegistryBuilder::hook;
+    let _: fn(&mut RegistryBuilder, Vec<Option<HashMap<u8, Vec<u8>>>>) -> &mut RegistryBuilder =
+        RegistryBuilder::nested;
+
+    let mut counts = HashMap::new();
+    counts.insert("builds".to_owned(), 3);
+    let registry = Registry::builder()
+        .counts(counts)
+        .last(Err("timeout".to_owned()))
+        .hook(Box::new(|n| n + 1))
+        .nested(vec![None])
+        .build()
+        .unwrap();
+    assert_eq!(registry.counts["builds"], 3);
+    assert_eq!(registry.last, Err("timeout".to_owned()));
+    assert_eq!((registry.hook)(1), 2);
+    assert_eq!(registry.nested, vec![None]);
+}
@@ -0,0 +1,29 @@
+// Two fields collected with the same `each` name would generate two methods
+// of the same name. Every name the builder generates is checked, including
+// `each` names against plain setters and the `with_` variants, and the error
+// points at the second field's name. No setter may take the name of the
+// builder's own `build` methods either.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Cart {
+    #[builder(each = "item")]
+    fruits: Vec<String>,
+    #[builder(each = "item")]
+    vegetables: Vec<String>,
+}
+
+#[derive(Builder)]
+pub struct Request {
+    body: String,
+    with_body: bool,
+}
+
+#[derive(Builder)]
+pub struct Job {
+    #[builder(each = "build")]
+    steps: Vec<String>,
+}
+
+fn main() {}
@@ -0,0 +1,30 @@
+// A type parameter with a default. The builder struct repeats the parameter
+// together with its default, so `CfgBuilder` alone names `CfgBuilder<String>`
+// just like `Cfg` names `Cfg<String>`. Defaults are not allowed on impl
+// blocks, so every impl of the builder lists the parameter without it.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Cfg<T = String> {
+    name: T,
+    alias: Option<T>,
+}
+
+fn main() {
+    let cfg: Cfg = Cfg::builder()
+        .name("primary".to_owned())
+        .alias("main".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(cfg.name, "primary");
+    assert_eq!(cfg.alias.as_deref(), Some("main"));
+
+    let mut builder: CfgBuilder = Cfg::builder();
+    builder.name("fallback".to_owned());
+    let cfg: Cfg<String> = builder.build().unwrap();
+    assert_eq!(cfg.name, "fallback");
+
+    let numbered = Cfg::builder().name(7).build().unwrap();
+    assert_eq!(numbered.name + 1, 8);
+}
@@ -0,0 +1,42 @@
+// One builder can be reused for many values. `reset` clears every field back
+// to how a new builder starts out: required and Option fields unset, and
+// fields collected with `each` empty.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(default = "1")]
+    jobs: u32,
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .jobs(4)
+        .current_dir("..".to_owned());
+    let first = builder.build().unwrap();
+    assert_eq!(first.args, ["build"]);
+    assert_eq!(first.jobs, 4);
+
+    builder.executable("rustc".to_owned()).arg("--version".to_owned());
+    builder.reset();
+    assert!(builder.try_build().is_none());
+
+    let second = builder
+        .reset()
+        .executable("git".to_owned())
+        .arg("status".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(second.executable, "git");
+    assert_eq!(second.args, ["status"]);
+    assert_eq!(second.jobs, 1);
+    assert!(second.current_dir.is_none());
+}
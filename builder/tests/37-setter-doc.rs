@@ -0,0 +1,34 @@
+// `#[builder(setter(doc = "..."))]` documents a field's setters with a string
+// of its own. It comes before any doc comments copied from the field, so an
+// undocumented field can still get documented setters.
+//
+// Missing docs are denied, so the build fails if a setter is left without.
+
+#![deny(missing_docs)]
+//! Documented setters.
+
+use derive_builder::Builder;
+
+/// A user account.
+#[derive(Builder)]
+pub struct Account {
+    #[builder(setter(doc = "Sets the name."))]
+    name: String,
+    /// Groups the account belongs to.
+    #[builder(each = "group", setter(doc = "Adds the account to groups."))]
+    groups: Vec<String>,
+    #[builder(setter(vis = "pub", doc = "Sets the home directory."))]
+    home: Option<String>,
+}
+
+fn main() {
+    let account = Account::builder()
+        .name("ferris".to_owned())
+        .group("wheel".to_owned())
+        .home("/home/ferris".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(account.name, "ferris");
+    assert_eq!(account.groups, ["wheel"]);
+    assert_eq!(account.home.as_deref(), Some("/home/ferris"));
+}
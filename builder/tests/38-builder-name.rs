@@ -0,0 +1,36 @@
+// `#[builder(name = "...")]` names the builder struct. Only attributes named
+// `builder` are read; derives, `repr`, `cfg_attr`, lint levels and docs on
+// the struct are left to the compiler, whether they come before or after.
+
+use derive_builder::Builder;
+
+/// A packet header.
+#[derive(Builder, Debug, Clone, PartialEq)]
+#[repr(C)]
+#[builder(name = "HeaderSpec")]
+#[cfg_attr(test, derive(Default))]
+#[allow(clippy::struct_field_names)]
+pub struct Header {
+    tag: u8,
+    length: u32,
+}
+
+#[allow(dead_code)]
+#[derive(Builder)]
+#[builder(derive(Debug))]
+#[builder(name = "Opts")]
+#[must_use]
+pub struct Options {
+    verbose: bool,
+}
+
+fn main() {
+    let mut spec: HeaderSpec = Header::builder();
+    spec.tag(1).length(20);
+    let header = spec.build().unwrap();
+    assert_eq!(header, Header { tag: 1, length: 20 });
+
+    let options = Opts::default().with_verbose(true).build().unwrap();
+    assert!(options.verbose);
+    assert_eq!(format!("{:?}", Options::builder()), "Opts { verbose: None }");
+}
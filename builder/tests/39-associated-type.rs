@@ -0,0 +1,46 @@
+// Field types may be associated types of a type parameter. They are stored and
+// set as written, and the bound that makes `T::Item` mean something, whether
+// declared on the parameter or in a where clause, is carried to every impl of
+// the builder.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Cursor<T: Iterator> {
+    source: T,
+    current: T::Item,
+    peeked: Option<T::Item>,
+}
+
+#[derive(Builder)]
+pub struct Batch<T>
+where
+    T: IntoIterator,
+    T::Item: Clone,
+{
+    #[builder(each = "item")]
+    items: Vec<T::Item>,
+    first: <T as IntoIterator>::Item,
+}
+
+fn main() {
+    let mut source = "a b c".split(' ');
+    let current = source.next().unwrap();
+    let cursor = Cursor::builder()
+        .current(current)
+        .source(source)
+        .build()
+        .unwrap();
+    assert_eq!(cursor.current, "a");
+    assert!(cursor.peeked.is_none());
+    assert_eq!(cursor.source.collect::<Vec<_>>(), ["b", "c"]);
+
+    let batch = Batch::<Vec<u8>>::builder()
+        .item(1)
+        .item(2)
+        .first(0)
+        .build()
+        .unwrap();
+    assert_eq!(batch.items, [1, 2]);
+    assert_eq!(batch.first, 0);
+}
@@ -0,0 +1,59 @@
+// `#[builder(trait)]` implements a `HasBuilder` trait for the struct, so that
+// generic code can get hold of the builder of any type that has one. The
+// derive cannot provide the trait itself, so it is declared next to the
+// structs, and whatever `HasBuilder` is in scope is implemented. A trait with
+// another name or path is given as `#[builder(trait = "path::Trait")]`.
+//
+// The inherent `builder()` is still generated.
+
+use derive_builder::Builder;
+
+pub trait HasBuilder {
+    type Builder;
+
+    fn builder() -> Self::Builder;
+}
+
+mod traits {
+    pub trait Buildable {
+        type Builder;
+
+        fn builder() -> Self::Builder;
+    }
+}
+
+#[derive(Builder)]
+#[builder(trait)]
+pub struct Command {
+    executable: String,
+}
+
+#[derive(Builder)]
+#[builder(trait = "traits::Buildable")]
+pub struct Pool<T> {
+    items: Vec<T>,
+}
+
+fn make<T: HasBuilder>() -> T::Builder {
+    T::builder()
+}
+
+fn make_buildable<T: traits::Buildable>() -> T::Builder {
+    T::builder()
+}
+
+fn main() {
+    let command = make::<Command>()
+        .executable("cargo".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.executable, "cargo");
+
+    let pool: Pool<u8> = make_buildable::<Pool<u8>>()
+        .items(vec![1, 2])
+        .build()
+        .unwrap();
+    assert_eq!(pool.items, [1, 2]);
+
+    assert!(Command::builder().try_build().is_none());
+}
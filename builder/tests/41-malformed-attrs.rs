@@ -0,0 +1,80 @@
+// Malformed attributes are reported as compile errors pointing at the part
+// that is wrong, never as a panic inside the derive. A string that does not
+// parse as what its key expects names the key in the error.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct A {
+    #[builder]
+    a: String,
+}
+
+#[derive(Builder)]
+pub struct B {
+    #[builder = "each"]
+    b: Vec<String>,
+}
+
+#[derive(Builder)]
+pub struct C {
+    #[builder(each = 5)]
+    c: Vec<String>,
+}
+
+#[derive(Builder)]
+pub struct D {
+    #[builder(each = "two words")]
+    d: Vec<String>,
+}
+
+#[derive(Builder)]
+pub struct E {
+    #[builder(each = "d" "e")]
+    e: Vec<String>,
+}
+
+#[derive(Builder)]
+#[builder(name = "Not A Name")]
+pub struct F {
+    f: String,
+}
+
+#[derive(Builder)]
+#[builder(derive("Clone"))]
+pub struct G {
+    g: String,
+}
+
+#[derive(Builder)]
+pub struct H {
+    #[builder(setter(vis = "pub(nowhere)"))]
+    h: String,
+}
+
+#[derive(Builder)]
+pub struct I(String);
+
+#[derive(Builder)]
+pub struct J {
+    #[builder(skip, setter = "set_j")]
+    j: String,
+}
+
+#[derive(Builder)]
+pub struct K {
+    #[builder(wrap)]
+    k: Option<String>,
+}
+
+#[derive(Builder)]
+#[builder(derive_default, validate = "check_l")]
+pub struct L {
+    l: Option<String>,
+}
+
+fn check_l(_: &L) -> Result<(), std::fmt::Error> {
+    Ok(())
+}
+
+fn main() {}
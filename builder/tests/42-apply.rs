@@ -0,0 +1,35 @@
+// `apply` hands the builder to a closure and returns whatever the closure
+// returns, so a chain of setters can set a field only under some condition.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+fn command(release: bool, dir: Option<&str>) -> Command {
+    Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .apply(|b| if release { b.arg("--release".to_owned()) } else { b })
+        .apply(|b| match dir {
+            Some(dir) => b.current_dir(dir.to_owned()),
+            None => b,
+        })
+        .build()
+        .unwrap()
+}
+
+fn main() {
+    let debug = command(false, None);
+    assert_eq!(debug.args, ["build"]);
+    assert!(debug.current_dir.is_none());
+
+    let release = command(true, Some(".."));
+    assert_eq!(release.args, ["build", "--release"]);
+    assert_eq!(release.current_dir.as_deref(), Some(".."));
+}
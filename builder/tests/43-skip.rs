@@ -0,0 +1,38 @@
+// `#[builder(skip)]` leaves a field out of the builder: it gets no setter and
+// no storage, and is never required. `build` fills it with its Default value,
+// or with the expression given as `#[builder(skip = "expr")]`, which is
+// evaluated on every build.
+
+use derive_builder::Builder;
+use std::time::Duration;
+
+#[derive(Builder)]
+#[builder(derive(Clone))]
+pub struct Job {
+    name: String,
+    #[builder(skip)]
+    id: u64,
+    #[builder(skip = "vec![\"queued\".to_owned()]")]
+    history: Vec<String>,
+    // Does not implement Clone, which the builder's derive does not need.
+    #[builder(skip)]
+    handle: Handle,
+    timeout: Option<Duration>,
+}
+
+#[derive(Default)]
+pub struct Handle(Option<std::fs::File>);
+
+fn main() {
+    let job = Job::builder().name("index".to_owned()).build().unwrap();
+    assert_eq!(job.name, "index");
+    assert_eq!(job.id, 0);
+    assert_eq!(job.history, ["queued"]);
+    assert!(job.handle.0.is_none());
+    assert!(job.timeout.is_none());
+
+    let edited = Job::edit(Job { id: 7, ..job });
+    let rebuilt = edited.clone().build().unwrap();
+    assert_eq!(rebuilt.name, "index");
+    assert_eq!(rebuilt.id, 0);
+}
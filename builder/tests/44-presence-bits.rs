@@ -0,0 +1,50 @@
+// `#[builder(presence_bits)]` has the builder record which fields have been
+// set in a single u64, one bit per field, so that asking whether a field has
+// been set is a single bit test whatever its type. `is_set` takes the
+// field's position in the struct, counting from 0. A struct with more than 64
+// fields is rejected.
+//
+// Every setter of a field sets its bit, including the `each` setter. A
+// successful build moves the values out and clears the bits, and so does
+// `reset`. A builder made with `edit` has every bit set.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(presence_bits)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+    #[builder(default = "1")]
+    jobs: u32,
+}
+
+fn main() {
+    let mut builder = Command::builder();
+    assert!((0..4).all(|i| !builder.is_set(i)));
+
+    builder.executable("cargo".to_owned()).arg("build".to_owned());
+    assert!(builder.is_set(0));
+    assert!(builder.is_set(1));
+    assert!(!builder.is_set(2));
+    assert!(!builder.is_set(3));
+    assert!(!builder.is_set(64));
+
+    builder.jobs(4);
+    assert!(builder.is_set(3));
+
+    let command = builder.build().unwrap();
+    assert_eq!(command.jobs, 4);
+    assert!((0..4).all(|i| !builder.is_set(i)));
+
+    let edited = Command::edit(command);
+    assert!((0..4).all(|i| edited.is_set(i)));
+
+    let mut failed = Command::builder();
+    failed.current_dir("..".to_owned());
+    assert!(failed.build().is_err());
+    assert!(failed.is_set(2));
+    assert!(!failed.reset().is_set(2));
+}
@@ -0,0 +1,59 @@
+// `#[builder(build_fn = "name")]` renames the method building the struct, for
+// example when `build` already means something else for the struct's users.
+// `#[builder(build_fn(name = "...", error = "..."))]` also picks the error
+// type it returns, which is made from the missing field's message through
+// `From<&'static str>`. TryFrom goes through the renamed method and has the
+// same error type. `builder()` and the builder's name stay as they are.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(build_fn = "finish")]
+pub struct Command {
+    executable: String,
+    // A setter named like the default build method is fine once it is renamed.
+    build: bool,
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    Missing(&'static str),
+}
+
+impl From<&'static str> for ConfigError {
+    fn from(message: &'static str) -> Self {
+        ConfigError::Missing(message)
+    }
+}
+
+#[derive(Builder)]
+#[builder(build_fn(name = "assemble", error = "ConfigError"))]
+pub struct Config {
+    name: String,
+    port: Option<u16>,
+}
+
+fn main() {
+    let command: Command = Command::builder()
+        .executable("cargo".to_owned())
+        .build(true)
+        .finish()
+        .unwrap();
+    assert!(command.build);
+
+    let mut builder: ConfigBuilder = Config::builder();
+    assert_eq!(
+        builder.assemble().err(),
+        Some(ConfigError::Missing("field `name` is not set")),
+    );
+    let config = builder.name("web".to_owned()).assemble().unwrap();
+    assert_eq!(config.name, "web");
+    assert!(config.port.is_none());
+
+    let converted: Result<Config, ConfigError> =
+        std::convert::TryFrom::try_from(Config::builder().with_port(80));
+    assert_eq!(
+        converted.err(),
+        Some(ConfigError::Missing("field `name` is not set")),
+    );
+}
@@ -0,0 +1,37 @@
+// The build method's name has to be an identifier, and cannot take the name of
+// any other method of the builder, including `is_set` when it tracks which
+// fields are set.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(build_fn = "finish up")]
+pub struct Command {
+    executable: String,
+}
+
+#[derive(Builder)]
+#[builder(build_fn(name = "try_build"))]
+pub struct Config {
+    name: String,
+}
+
+#[derive(Builder)]
+#[builder(build_fn = "reset")]
+pub struct Session {
+    user: String,
+}
+
+#[derive(Builder)]
+#[builder(build_fn = "apply")]
+pub struct Patch {
+    diff: String,
+}
+
+#[derive(Builder)]
+#[builder(presence_bits, build_fn = "is_set")]
+pub struct Flags {
+    verbose: bool,
+}
+
+fn main() {}
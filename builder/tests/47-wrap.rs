@@ -0,0 +1,51 @@
+// Fields behind a `Box`, `Rc` or `Arc` take the pointer in their setter, like
+// any other required field. With `#[builder(wrap)]` the setter takes the value
+// itself and puts it behind the pointer, so callers don't have to.
+//
+//     fn config(&mut self, config: Config) -> &mut Self
+//
+// The builder still stores the pointer, so `edit` and `build` are unchanged.
+
+use derive_builder::Builder;
+use std::rc::Rc;
+use std::sync::Arc;
+
+#[derive(Debug, PartialEq)]
+pub struct Config {
+    verbose: bool,
+}
+
+#[derive(Builder)]
+pub struct Server {
+    #[builder(wrap)]
+    config: Arc<Config>,
+    #[builder(wrap)]
+    handler: Box<u32>,
+    #[builder(wrap, default = "Rc::new(8080)")]
+    port: Rc<u16>,
+    // Without the attribute the setter takes the Box.
+    name: Box<str>,
+}
+
+fn main() {
+    let config = Arc::new(Config { verbose: false });
+    let server = Server::builder()
+        .with_config(Config { verbose: true })
+        .with_handler(7)
+        .with_name("web".into())
+        .build()
+        .unwrap();
+    assert_eq!(*server.config, Config { verbose: true });
+    assert_eq!(*server.handler, 7);
+    assert_eq!(*server.port, 8080);
+    assert_eq!(&*server.name, "web");
+
+    let server = Server::edit(server).port(443).build().unwrap();
+    assert_eq!(*server.port, 443);
+    assert_eq!(*server.handler, 7);
+
+    // The stored pointer can still be replaced through `edit`.
+    let mut builder = Server::edit(server);
+    let server = builder.config(Config { verbose: false }).build().unwrap();
+    assert_eq!(server.config, config);
+}
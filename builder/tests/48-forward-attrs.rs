@@ -0,0 +1,39 @@
+// `#[builder(forward_attrs(serde))]` copies every `#[serde(...)]` attribute of
+// a field onto the builder's field, so that a builder deriving Deserialize
+// reads the same names as the struct. Other attributes stay on the struct.
+//
+// Builder fields are Options, so a builder can be read from a document
+// leaving some fields out and filled in with setters afterwards.
+
+use derive_builder::Builder;
+use serde::Deserialize;
+
+#[derive(Builder, Deserialize)]
+#[builder(forward_attrs(serde), derive(Deserialize))]
+pub struct Command {
+    #[serde(rename = "exe")]
+    executable: String,
+    #[builder(each = "arg")]
+    #[serde(rename = "argv", default)]
+    args: Vec<String>,
+    /// Directory to run in.
+    #[serde(rename = "cwd")]
+    current_dir: Option<String>,
+}
+
+fn main() {
+    let mut builder: CommandBuilder =
+        serde_json::from_str(r#"{"exe": "cargo", "argv": ["build"]}"#).unwrap();
+    let command = builder.arg("--release".to_owned()).build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build", "--release"]);
+    assert_eq!(command.current_dir, None);
+
+    // The struct reads the same document.
+    let command: Command =
+        serde_json::from_str(r#"{"exe": "cargo", "argv": [], "cwd": ".."}"#).unwrap();
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+
+    let mut builder: CommandBuilder = serde_json::from_str(r#"{"cwd": ".."}"#).unwrap();
+    assert!(builder.build().is_err());
+}
@@ -0,0 +1,47 @@
+// Field types only need to be visible where the struct is. The builder's own
+// fields are private, so a private type behind a field, or one declared in the
+// function around the struct, works the same as it does in the struct and
+// causes no private-in-public error.
+
+use derive_builder::Builder;
+
+mod inner {
+    use derive_builder::Builder;
+
+    // Private to this module.
+    #[derive(Debug, PartialEq)]
+    struct Secret(u32);
+
+    #[derive(Builder)]
+    pub struct Config {
+        name: String,
+        secret: Secret,
+    }
+
+    pub fn make() -> Config {
+        Config::builder()
+            .name("db".to_owned())
+            .secret(Secret(7))
+            .build()
+            .unwrap()
+    }
+
+    pub fn check(config: &Config) -> bool {
+        config.secret == Secret(7)
+    }
+}
+
+fn main() {
+    // Only nameable inside main.
+    #[derive(Debug, PartialEq)]
+    struct Local(u8);
+
+    #[derive(Builder)]
+    pub struct Wrapper {
+        local: Local,
+    }
+
+    let wrapper = Wrapper::builder().local(Local(1)).build().unwrap();
+    assert_eq!(wrapper.local, Local(1));
+    assert!(inner::check(&inner::make()));
+}
@@ -0,0 +1,55 @@
+// `build_or_default` builds the struct without ever failing: required fields
+// that have not been set take their type's Default, while fields with a
+// default of their own, Option fields and `each` fields behave as in `build`.
+//
+// Like `try_build`, it borrows the builder and clones the values set so far,
+// leaving them in place. Field types need Clone, and those of required fields
+// Default, but only for this method, so a struct with a field lacking either
+// keeps `build`.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(default = "4")]
+    jobs: u32,
+    retries: u8,
+    current_dir: Option<String>,
+}
+
+pub struct Handle;
+
+#[derive(Builder)]
+pub struct Job {
+    handle: Handle,
+}
+
+fn main() {
+    let command = Command::builder()
+        .arg("build".to_owned())
+        .retries(3)
+        .build_or_default();
+    assert_eq!(command.executable, "");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.jobs, 4);
+    assert_eq!(command.retries, 3);
+    assert_eq!(command.current_dir, None);
+
+    // The builder still holds its values afterwards.
+    let mut builder = Command::builder();
+    builder
+        .executable("cargo".to_owned())
+        .arg("test".to_owned())
+        .retries(1);
+    assert_eq!(builder.build_or_default().executable, "cargo");
+    let command = builder.build_or_default();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["test"]);
+    assert_eq!(builder.build().unwrap().executable, "cargo");
+
+    let job = Job::builder().handle(Handle).build();
+    assert!(job.is_ok());
+}
@@ -0,0 +1,104 @@
+// `#[builder(validate = "path")]` checks invariants spanning several fields,
+// which defaults for single fields cannot express. The function is called
+// with the built struct once every required field is known to be set, and
+// an error it returns is what `build` returns, converted into the builder's
+// error type with `From`. `try_build` gives None in that case, and
+// `build_or_default` returns a Result instead of the bare struct, since the
+// defaults it fills in can be rejected too. A validator cannot be combined
+// with `derive_default`, since Default has no way to report a rejection; see
+// 41-malformed-attrs.rs.
+//
+//     fn check(range: &Range) -> Result<(), RangeError>
+
+use derive_builder::Builder;
+use std::fmt::{self, Display};
+
+#[derive(Debug)]
+pub struct RangeError {
+    low: u32,
+    high: u32,
+}
+
+impl Display for RangeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "empty range {}..{}", self.low, self.high)
+    }
+}
+
+impl std::error::Error for RangeError {}
+
+#[derive(Builder, Debug)]
+#[builder(validate = "check_range")]
+pub struct Range {
+    low: u32,
+    high: u32,
+}
+
+fn check_range(range: &Range) -> Result<(), RangeError> {
+    if range.low < range.high {
+        Ok(())
+    } else {
+        Err(RangeError {
+            low: range.low,
+            high: range.high,
+        })
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum ConfigError {
+    Missing(&'static str),
+    Invalid(String),
+}
+
+impl From<&'static str> for ConfigError {
+    fn from(message: &'static str) -> Self {
+        ConfigError::Missing(message)
+    }
+}
+
+#[derive(Builder, Debug)]
+#[builder(build_fn(error = "ConfigError"), validate = "Config::check")]
+pub struct Config {
+    name: String,
+    replicas: Option<u8>,
+}
+
+impl Config {
+    fn check(&self) -> Result<(), ConfigError> {
+        match self.replicas {
+            Some(0) => Err(ConfigError::Invalid(format!("{} has no replicas", self.name))),
+            _ => Ok(()),
+        }
+    }
+}
+
+fn main() {
+    let range = Range::builder().low(1).high(3).build().unwrap();
+    assert_eq!((range.low, range.high), (1, 3));
+
+    let err = Range::builder().low(3).high(3).build().unwrap_err();
+    assert_eq!(err.to_string(), "empty range 3..3");
+    assert!(Range::builder().low(5).high(1).try_build().is_none());
+
+    // The defaults filled in by build_or_default go through the validator.
+    let err = Range::builder().low(5).build_or_default().unwrap_err();
+    assert_eq!(err.to_string(), "empty range 5..0");
+    let range = Range::builder().high(2).build_or_default().unwrap();
+    assert_eq!((range.low, range.high), (0, 2));
+
+    // A missing field is reported before the validator runs.
+    let err = Range::builder().high(0).build().unwrap_err();
+    assert_eq!(err.to_string(), "field `low` is not set");
+
+    let config = Config::builder().name("web".to_owned()).build().unwrap();
+    assert_eq!(config.replicas, None);
+    assert_eq!(
+        Config::builder()
+            .name("db".to_owned())
+            .replicas(0)
+            .build()
+            .unwrap_err(),
+        ConfigError::Invalid("db has no replicas".to_owned()),
+    );
+}
@@ -0,0 +1,36 @@
+// A skipped field is not stored in the builder, so it may be the only field
+// using a type parameter or lifetime, as with a `PhantomData` marker. The
+// builder then gets a private marker of its own, so that the parameter is
+// still used, sized or not, and the builder compiles.
+
+use derive_builder::Builder;
+use std::marker::PhantomData;
+
+pub struct Meters;
+
+#[derive(Builder)]
+pub struct Length<Unit> {
+    value: f64,
+    #[builder(skip)]
+    unit: PhantomData<Unit>,
+}
+
+#[derive(Builder)]
+pub struct Borrowed<'a, T: ?Sized, U> {
+    id: u32,
+    #[builder(skip)]
+    marker: PhantomData<(&'a T, U)>,
+}
+
+fn main() {
+    let length: Length<Meters> = Length::builder().value(2.5).build().unwrap();
+    assert_eq!(length.value, 2.5);
+    let _: PhantomData<Meters> = length.unit;
+
+    let length = Length::edit(length).value(3.0).build().unwrap();
+    assert_eq!(length.value, 3.0);
+
+    let borrowed: Borrowed<'static, str, u8> = Borrowed::builder().id(7).build().unwrap();
+    assert_eq!(borrowed.id, 7);
+    let _: PhantomData<(&str, u8)> = borrowed.marker;
+}
@@ -0,0 +1,108 @@
+// The generated builder is linted along with the code around it, so it has
+// to be clean under `clippy::pedantic` for crates that deny those lints.
+// Clippy checks this file as part of `cargo clippy --all-targets`, which is
+// why it is its own test target rather than a trybuild case.
+//
+// `#![deny(clippy::pedantic)]` only takes effect when clippy runs, so under a
+// plain `cargo test` this target checks nothing about lints. The clippy job
+// in CI runs `cargo clippy --workspace --all-targets -- -D warnings` for it.
+//
+// The structs between them use every option whose generated code carries its
+// own statements or `#[allow]`s.
+
+#![deny(clippy::pedantic)]
+
+use derive_builder::Builder;
+use serde::Deserialize;
+
+#[derive(Builder)]
+#[builder(derive(Clone, Debug))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    #[builder(default = "8")]
+    jobs: u32,
+    current_dir: Option<String>,
+}
+
+#[derive(Builder)]
+pub struct Pool<'a, T> {
+    items: Vec<T>,
+    name: &'a str,
+}
+
+#[derive(Debug)]
+pub struct JobError(String);
+
+impl From<&'static str> for JobError {
+    fn from(message: &'static str) -> Self {
+        JobError(message.to_owned())
+    }
+}
+
+#[derive(Builder, Debug)]
+#[builder(presence_bits, validate = "Job::check")]
+#[builder(build_fn(name = "submit", error = "JobError"))]
+pub struct Job {
+    name: String,
+    #[builder(wrap)]
+    limit: Box<u32>,
+    #[builder(skip)]
+    attempts: u32,
+}
+
+impl Job {
+    fn check(&self) -> Result<(), JobError> {
+        if *self.limit == 0 {
+            Err(JobError(format!("{} has no limit", self.name)))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[derive(Builder, Deserialize)]
+#[builder(forward_attrs(serde), derive(Deserialize))]
+pub struct Manifest {
+    #[serde(rename = "pkg")]
+    package: String,
+    #[builder(each = "feature")]
+    #[serde(default)]
+    features: Vec<String>,
+}
+
+#[test]
+fn builds_under_pedantic_lints() {
+    let command = Command::builder()
+        .executable("cargo".to_owned())
+        .arg("build".to_owned())
+        .build()
+        .unwrap();
+    assert_eq!(command.jobs, 8);
+    assert!(command.current_dir.is_none());
+    assert_eq!(command.args, ["build"]);
+
+    let pool = Pool::builder()
+        .items(vec![1, 2])
+        .name("pool")
+        .build()
+        .unwrap();
+    assert_eq!(pool.items.len(), 2);
+    assert_eq!(pool.name, "pool");
+
+    let mut job = Job::builder();
+    job.name("nightly".to_owned()).limit(3);
+    assert!(job.is_set(0) && job.is_set(1));
+    let built = job.try_build().unwrap();
+    assert_eq!(*built.limit, 3);
+    assert_eq!(built.attempts, 0);
+    assert!(job.build_or_default().is_ok());
+    let err = job.limit(0).submit().unwrap_err();
+    assert_eq!(err.0, "nightly has no limit");
+
+    let mut manifest: ManifestBuilder = serde_json::from_str(r#"{"pkg": "core"}"#).unwrap();
+    let manifest = manifest.feature("std".to_owned()).build().unwrap();
+    assert_eq!(manifest.package, "core");
+    assert_eq!(manifest.features, ["std"]);
+}
@@ -8,9 +8,52 @@ fn tests() {
     t.pass("tests/04-call-build.rs");
     t.pass("tests/05-method-chaining.rs");
     t.pass("tests/06-optional-field.rs");
-    // t.pass("tests/07-repeated-field.rs");
-    //t.compile_fail("tests/08-unrecognized-attribute.rs");
+    t.pass("tests/07-repeated-field.rs");
+    t.compile_fail("tests/08-unrecognized-attribute.rs");
     //t.pass("tests/09-redefined-prelude-types.rs");
+    t.pass("tests/10-try-build.rs");
+    t.pass("tests/11-visibility.rs");
+    t.pass("tests/12-field-default.rs");
+    t.pass("tests/13-try-from.rs");
+    t.pass("tests/14-after-build.rs");
+    t.compile_fail("tests/15-each-non-vec.rs");
+    t.pass("tests/16-derive-default.rs");
+    t.compile_fail("tests/17-derive-default-required.rs");
+    t.pass("tests/18-field-default-expr.rs");
+    t.compile_fail("tests/19-field-default-invalid.rs");
+    t.pass("tests/20-setter-vis.rs");
+    t.compile_fail("tests/21-setter-vis-private.rs");
+    t.pass("tests/22-non-clone-field.rs");
+    t.pass("tests/23-setter-rename.rs");
+    t.compile_fail("tests/24-setter-collision.rs");
+    t.pass("tests/25-generic-struct.rs");
+    t.pass("tests/26-nested-option.rs");
+    t.compile_fail("tests/27-unknown-struct-attr.rs");
+    t.pass("tests/28-setter-docs.rs");
+    t.pass("tests/29-repr-c.rs");
+    t.pass("tests/30-owned-setters.rs");
+    t.compile_fail("tests/31-derive-clone-hint.rs");
+    t.pass("tests/32-edit.rs");
+    t.pass("tests/33-verbatim-types.rs");
+    t.compile_fail("tests/34-duplicate-each.rs");
+    t.pass("tests/35-default-type-param.rs");
+    t.pass("tests/36-reset.rs");
+    t.pass("tests/37-setter-doc.rs");
+    t.pass("tests/38-builder-name.rs");
+    t.pass("tests/39-associated-type.rs");
+    t.pass("tests/40-builder-trait.rs");
+    t.compile_fail("tests/41-malformed-attrs.rs");
+    t.pass("tests/42-apply.rs");
+    t.pass("tests/43-skip.rs");
+    t.pass("tests/44-presence-bits.rs");
+    t.pass("tests/45-build-fn.rs");
+    t.compile_fail("tests/46-build-fn-invalid.rs");
+    t.pass("tests/47-wrap.rs");
+    t.pass("tests/48-forward-attrs.rs");
+    t.pass("tests/49-private-types.rs");
+    t.pass("tests/50-build-or-default.rs");
+    t.pass("tests/51-validate.rs");
+    t.pass("tests/52-skip-phantom.rs");
 }
 
 // #[test]
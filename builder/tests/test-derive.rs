@@ -0,0 +1,35 @@
+// `#[builder(test_derive(...))]` derives extra traits for the builder only
+// when compiling under `cfg(test)`, for example to compare builders in
+// assertions without adding those impls to regular builds.
+//
+// Unlike the numbered tests, which trybuild compiles as ordinary binaries,
+// this file is its own test target so that `cfg(test)` is set.
+
+use derive_builder::Builder;
+
+#[derive(Builder)]
+#[builder(test_derive(PartialEq, Eq))]
+pub struct Command {
+    executable: String,
+    #[builder(each = "arg")]
+    args: Vec<String>,
+    current_dir: Option<String>,
+}
+
+#[test]
+fn builders_compare_equal() {
+    let mut left = Command::builder();
+    left.executable("cargo".to_owned()).arg("build".to_owned());
+
+    let mut right = Command::builder();
+    right.arg("build".to_owned()).executable("cargo".to_owned());
+    assert!(left == right);
+
+    right.current_dir("..".to_owned());
+    assert!(left != right);
+
+    let command = right.build().unwrap();
+    assert_eq!(command.executable, "cargo");
+    assert_eq!(command.args, vec!["build"]);
+    assert_eq!(command.current_dir.as_deref(), Some(".."));
+}
@@ -1,10 +1,785 @@
 extern crate proc_macro;
 
 use proc_macro::TokenStream;
+use proc_macro2::{TokenStream as TokenStream2, TokenTree};
+use quote::quote;
+use std::ops::Index;
+use syn::punctuated::Punctuated;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DataStruct, DeriveInput, Field, Fields,
+    FieldsNamed, GenericArgument, Generics, Ident, Lit, LitStr, Meta, MetaList, NestedMeta,
+    PathArguments, Token, Type, TypeParamBound, TypePath, WherePredicate,
+};
 
-#[proc_macro_derive(CustomDebug)]
+#[proc_macro_derive(CustomDebug, attributes(debug))]
 pub fn derive(input: TokenStream) -> TokenStream {
-    let _ = input;
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
+    match &input.data {
+        Data::Struct(data) => impl_debug(input, data),
+        Data::Enum(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "CustomDebug cannot be derived for enums",
+        )),
+        Data::Union(_) => Err(syn::Error::new_spanned(
+            &input.ident,
+            "CustomDebug cannot be derived for unions",
+        )),
+    }
+}
+
+fn impl_debug(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream2> {
+    if collect_struct_options(&input.attrs)?.transparent {
+        return impl_transparent(input, data);
+    }
+
+    let named_fields = match &data.fields {
+        Fields::Named(fields) => fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "CustomDebug only supports structs with named fields",
+            ))
+        }
+    };
+
+    let struct_name = &input.ident;
+    let formats = collect_fields_format(named_fields)?;
+    let options = collect_fields_options(named_fields)?;
+
+    let body = match collect_struct_format(&input.attrs)? {
+        Some(template) => {
+            if let Some(attr) = named_fields
+                .named
+                .iter()
+                .find_map(|f| debug_attrs(&f.attrs).next())
+            {
+                return Err(syn::Error::new_spanned(
+                    attr,
+                    "field format has no effect when the struct has a #[debug = \"...\"] format",
+                ));
+            }
+            format_debug_template(named_fields, &template)?
+        }
+        None => {
+            let debug_fields = format_debug_fields(named_fields, &formats, &options)?;
+            quote! {{
+                let mut debug = f.debug_struct(stringify!(#struct_name));
+                #(#debug_fields)*
+                debug.finish()
+            }}
+        }
+    };
+
+    // Formatting through `{:?}` hands the body a fresh formatter without the
+    // alternate flag, so nothing nested inside switches to multi-line output.
+    let body = if collect_struct_options(&input.attrs)?.compact {
+        quote! {
+            struct Compact<F>(F);
+            impl<F> ::std::fmt::Debug for Compact<F>
+            where
+                F: Fn(&mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result,
+            {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    (self.0)(f)
+                }
+            }
+            write!(f, "{:?}", Compact(|f: &mut ::std::fmt::Formatter<'_>| #body))
+        }
+    } else {
+        body
+    };
+
+    // Redacted fields are never formatted, so their types play no part in the
+    // bounds.
+    let printed: Vec<&Field> = named_fields
+        .named
+        .iter()
+        .filter(|f| !options[f.ident.as_ref().unwrap()].redact)
+        .collect();
+    // A custom bound takes over from inference for the type parameters it
+    // mentions, while the other parameters are still inferred. `no_bound`, or
+    // an empty `bound = ""`, turns inference off altogether.
+    let handwritten = collect_custom_bound_attr(&input.attrs)?;
+    let params: Vec<Ident> = match &handwritten {
+        Some(bounds) if bounds.is_empty() => Vec::new(),
+        _ => input
+            .generics
+            .type_params()
+            .map(|p| p.ident.clone())
+            .filter(|p| {
+                !handwritten
+                    .iter()
+                    .flatten()
+                    .any(|predicate| mentions_param_tokens(quote!(#predicate), p))
+            })
+            .collect(),
+    };
+    let mut generics = input.generics.clone();
+    let display_fields: Vec<&Field> = printed
+        .iter()
+        .copied()
+        .filter(|f| options[f.ident.as_ref().unwrap()].display)
+        .collect();
+    // A type parameter used directly gets `T: Debug`, and one used through an
+    // associated type gets `T::Value: Debug`; a parameter used both ways gets
+    // both. Only type parameters are bounded. Lifetimes and const parameters
+    // go through split_for_impl as declared.
+    for param in generics.type_params_mut() {
+        let ident = &param.ident;
+        if !params.contains(ident) {
+            continue;
+        }
+        // Only uses outside of PhantomData, in fields printed through Debug,
+        // call for a bound. A parameter only reachable through
+        // `#[debug(display)]` fields needs Display, which is required below.
+        let debugged = printed
+            .iter()
+            .filter(|f| !options[f.ident.as_ref().unwrap()].display)
+            .any(|f| mentions_param_outside_phantom(&f.ty, ident));
+        if !debugged || declares_bound(&input.generics, &parse_quote!(#ident), "Debug") {
+            continue;
+        }
+        param.bounds.push(parse_quote!(::std::fmt::Debug));
+    }
+    let mut inferred: Vec<WherePredicate> = Vec::new();
+    for assoc in collect_associated_types(&printed, &params) {
+        let ty = Type::Path(assoc);
+        if !declares_bound(&input.generics, &ty, "Debug") {
+            inferred.push(parse_quote!(#ty: ::std::fmt::Debug));
+        }
+    }
+    for field in display_fields {
+        let ty = &field.ty;
+        if params.iter().any(|p| mentions_param(ty, p))
+            && !declares_bound(&input.generics, ty, "Display")
+        {
+            inferred.push(parse_quote!(#ty: ::std::fmt::Display));
+        }
+    }
+    // The struct's own predicates come first and are kept as written, then the
+    // custom ones; an inferred one is only added once.
+    let where_clause = generics.make_where_clause();
+    where_clause
+        .predicates
+        .extend(handwritten.into_iter().flatten());
+    for predicate in inferred {
+        let text = quote!(#predicate).to_string();
+        if !where_clause
+            .predicates
+            .iter()
+            .any(|p| quote!(#p).to_string() == text)
+        {
+            where_clause.predicates.push(predicate);
+        }
+    }
+    finish_impl(input, &generics, body)
+}
+
+// Delegates to the only field, so the wrapper leaves no trace in the output.
+fn impl_transparent(input: &DeriveInput, data: &DataStruct) -> syn::Result<TokenStream2> {
+    let mut fields = data.fields.iter();
+    let field = match (fields.next(), fields.next()) {
+        (Some(field), None) => field,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "#[debug(transparent)] requires a struct with exactly one field",
+            ))
+        }
+    };
+    if let Some(attr) = debug_attrs(&field.attrs).next() {
+        return Err(syn::Error::new_spanned(
+            attr,
+            "field options have no effect on a #[debug(transparent)] struct",
+        ));
+    }
+    // The field prints itself, so nothing about the struct's own formatting
+    // can apply.
+    if let Some(template) = collect_struct_format(&input.attrs)? {
+        return Err(syn::Error::new_spanned(
+            template,
+            "struct format has no effect on a #[debug(transparent)] struct",
+        ));
+    }
+    if let Some(compact) = struct_options(&input.attrs)?.into_iter().find(
+        |nested| matches!(nested, NestedMeta::Meta(Meta::Path(path)) if path.is_ident("compact")),
+    ) {
+        return Err(syn::Error::new_spanned(
+            compact,
+            "#[debug(compact)] cannot be combined with #[debug(transparent)]",
+        ));
+    }
+    let member = match &field.ident {
+        Some(ident) => quote!(#ident),
+        None => quote!(0),
+    };
+
+    let mut generics = input.generics.clone();
+    let ty = &field.ty;
+    let predicates = match collect_custom_bound_attr(&input.attrs)? {
+        Some(bounds) => bounds,
+        None => vec![parse_quote!(#ty: ::std::fmt::Debug)],
+    };
+    generics.make_where_clause().predicates.extend(predicates);
+    let body = quote! { ::std::fmt::Debug::fmt(&self.#member, f) };
+    finish_impl(input, &generics, body)
+}
+
+// Wraps the body in the Debug impl, or with `#[debug(impl_name = "...")]` in
+// an inherent method of that name, which avoids conflicting with a Debug impl
+// the type already has.
+fn finish_impl(
+    input: &DeriveInput,
+    generics: &Generics,
+    body: TokenStream2,
+) -> syn::Result<TokenStream2> {
+    let struct_name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+    match collect_struct_options(&input.attrs)?.impl_name {
+        Some(name) => {
+            let vis = &input.vis;
+            Ok(quote! {
+                impl #impl_generics #struct_name #ty_generics #where_clause {
+                    #vis fn #name(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                        #body
+                    }
+                }
+            })
+        }
+        None => Ok(quote! {
+            impl #impl_generics ::std::fmt::Debug for #struct_name #ty_generics #where_clause {
+                fn fmt(&self, f: &mut ::std::fmt::Formatter<'_>) -> ::std::fmt::Result {
+                    #body
+                }
+            }
+        }),
+    }
+}
+
+// Whether the struct's generics already require `ty` to implement the trait,
+// either on the parameter or in the where clause. Traits are compared by the
+// last segment of their path, so `Debug` matches `std::fmt::Debug`.
+fn declares_bound(generics: &Generics, ty: &Type, trait_name: &str) -> bool {
+    let ty = quote!(#ty).to_string();
+    let has_trait = |bounds: &Punctuated<TypeParamBound, Token![+]>| {
+        bounds.iter().any(|bound| match bound {
+            TypeParamBound::Trait(t) => t
+                .path
+                .segments
+                .last()
+                .is_some_and(|s| s.ident == trait_name),
+            _ => false,
+        })
+    };
+    let on_param = generics.type_params().any(|param| {
+        let ident = &param.ident;
+        quote!(#ident).to_string() == ty && has_trait(&param.bounds)
+    });
+    let in_where = generics
+        .where_clause
+        .iter()
+        .flat_map(|w| &w.predicates)
+        .any(|predicate| match predicate {
+            WherePredicate::Type(p) => {
+                let bounded = &p.bounded_ty;
+                quote!(#bounded).to_string() == ty && has_trait(&p.bounds)
+            }
+            _ => false,
+        });
+    on_param || in_where
+}
+
+fn debug_attrs(attrs: &[Attribute]) -> impl Iterator<Item = &Attribute> {
+    attrs.iter().filter(|attr| attr.path.is_ident("debug"))
+}
+
+// Per-field values kept in the order they were inserted, which is declaration
+// order, so anything iterating over them expands the same way every time.
+struct FieldMap<T>(Vec<(Ident, T)>);
+
+impl<T> Default for FieldMap<T> {
+    fn default() -> Self {
+        FieldMap(Vec::new())
+    }
+}
+
+impl<T> FieldMap<T> {
+    // Replaces the value of a field already present in place, keeping its
+    // position.
+    fn insert(&mut self, ident: Ident, value: T) {
+        match self.0.iter_mut().find(|(key, _)| *key == ident) {
+            Some((_, old)) => *old = value,
+            None => self.0.push((ident, value)),
+        }
+    }
+
+    fn get(&self, ident: &Ident) -> Option<&T> {
+        self.0
+            .iter()
+            .find(|(key, _)| key == ident)
+            .map(|(_, value)| value)
+    }
+}
+
+impl<T> Index<&Ident> for FieldMap<T> {
+    type Output = T;
+
+    fn index(&self, ident: &Ident) -> &T {
+        self.get(ident).expect("no entry for field")
+    }
+}
+
+// A field's custom format and the fields passed to it as arguments.
+struct FieldFormat {
+    lit: Lit,
+    args: Vec<Ident>,
+}
+
+// Reads the format of each field, keyed by field name. `#[debug = "..."]`, or
+// `#[debug(format = "...")]` among other options, formats the field itself,
+// while `#[debug("{}.{}", major, minor)]` formats the listed fields of the
+// struct.
+fn collect_fields_format(fields: &FieldsNamed) -> syn::Result<FieldMap<FieldFormat>> {
+    let mut formats = FieldMap::default();
+    for field in &fields.named {
+        let ident = field.ident.clone().unwrap();
+        for attr in debug_attrs(&field.attrs) {
+            match attr.parse_meta()? {
+                Meta::NameValue(nv) => {
+                    let args = vec![ident.clone()];
+                    formats.insert(ident.clone(), FieldFormat { lit: nv.lit, args });
+                }
+                Meta::List(list) if is_format_list(&list) => {
+                    let mut nested = list.nested.iter();
+                    let lit = match nested.next() {
+                        Some(NestedMeta::Lit(lit)) => lit.clone(),
+                        _ => unreachable!(),
+                    };
+                    let args = nested
+                        .map(|arg| match arg {
+                            NestedMeta::Meta(Meta::Path(path)) => path
+                                .get_ident()
+                                .filter(|arg| {
+                                    fields.named.iter().any(|f| f.ident.as_ref() == Some(arg))
+                                })
+                                .cloned()
+                                .ok_or_else(|| {
+                                    syn::Error::new_spanned(path, "expected a field of this struct")
+                                }),
+                            _ => Err(syn::Error::new_spanned(arg, "expected a field name")),
+                        })
+                        .collect::<syn::Result<_>>()?;
+                    formats.insert(ident.clone(), FieldFormat { lit, args });
+                }
+                Meta::List(list) => {
+                    for nested in &list.nested {
+                        if let NestedMeta::Meta(Meta::NameValue(nv)) = nested {
+                            if nv.path.is_ident("format") {
+                                let args = vec![ident.clone()];
+                                let lit = nv.lit.clone();
+                                formats.insert(ident.clone(), FieldFormat { lit, args });
+                            }
+                        }
+                    }
+                }
+                meta => return Err(syn::Error::new_spanned(meta, "expected `debug = \"...\"`")),
+            }
+        }
+    }
+    Ok(formats)
+}
+
+// Whether a `#[debug(...)]` list is a format with arguments rather than a set
+// of options.
+fn is_format_list(list: &MetaList) -> bool {
+    matches!(list.nested.first(), Some(NestedMeta::Lit(_)))
+}
+
+// Per-field `#[debug(...)]` options.
+#[derive(Default)]
+struct FieldOptions {
+    // Print through Display instead of Debug.
+    display: bool,
+    // Read the value through a method named like the field, `self.field()`.
+    getter: bool,
+    // Printed name of the field in place of its identifier.
+    rename: Option<String>,
+    // Print a placeholder in place of the value, which is never read.
+    redact: bool,
+    // Leave the field out whenever this predicate, called with a reference to
+    // the value, returns true. It is kept as tokens, so closures work without
+    // syn's full expression parser.
+    skip_if: Option<TokenStream2>,
+}
+
+fn collect_fields_options(fields: &FieldsNamed) -> syn::Result<FieldMap<FieldOptions>> {
+    let mut options = FieldMap::default();
+    for field in &fields.named {
+        let mut opts = FieldOptions::default();
+        for attr in debug_attrs(&field.attrs) {
+            match attr.parse_meta()? {
+                Meta::List(list) if !is_format_list(&list) => {
+                    for nested in &list.nested {
+                        match nested {
+                            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("display") => {
+                                opts.display = true;
+                            }
+                            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("getter") => {
+                                opts.getter = true;
+                            }
+                            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("redact") => {
+                                opts.redact = true;
+                            }
+                            // Read by collect_fields_format.
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("format") => {
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv))
+                                if nv.path.is_ident("skip_if") =>
+                            {
+                                opts.skip_if = match &nv.lit {
+                                    Lit::Str(s) => Some(s.parse()?),
+                                    lit => {
+                                        return Err(syn::Error::new_spanned(
+                                            lit,
+                                            "expected a string literal",
+                                        ))
+                                    }
+                                };
+                            }
+                            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("rename") => {
+                                opts.rename = match &nv.lit {
+                                    Lit::Str(s) => Some(s.value()),
+                                    lit => {
+                                        return Err(syn::Error::new_spanned(
+                                            lit,
+                                            "expected a string literal",
+                                        ))
+                                    }
+                                };
+                            }
+                            _ => {
+                                return Err(syn::Error::new_spanned(
+                                    nested,
+                                    "unknown debug option, expected `format = \"...\"`, \
+                                     `rename = \"...\"`, `skip_if = \"...\"`, `display`, \
+                                     `getter` or `redact`",
+                                ))
+                            }
+                        }
+                    }
+                }
+                _ => {}
+            }
+        }
+        options.insert(field.ident.clone().unwrap(), opts);
+    }
+    Ok(options)
+}
+
+// Reads a struct-level `#[debug = "..."]` template such as "Point({x}, {y})".
+fn collect_struct_format(attrs: &[Attribute]) -> syn::Result<Option<LitStr>> {
+    for attr in debug_attrs(attrs) {
+        if let Meta::NameValue(nv) = attr.parse_meta()? {
+            return match nv.lit {
+                Lit::Str(s) => Ok(Some(s)),
+                lit => Err(syn::Error::new_spanned(lit, "expected a string literal")),
+            };
+        }
+    }
+    Ok(None)
+}
+
+// Reads `#[debug(bound = "...")]`, which replaces the bounds inferred for the
+// type parameters it mentions. Every `bound` adds its comma-separated
+// predicates, so `bound = ""` and `no_bound` leave the impl with only the
+// bounds the struct declares.
+fn collect_custom_bound_attr(attrs: &[Attribute]) -> syn::Result<Option<Vec<WherePredicate>>> {
+    let mut bounds = None;
+    for nested in struct_options(attrs)? {
+        match &nested {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("bound") => {
+                let predicates = match &nv.lit {
+                    Lit::Str(s) => {
+                        s.parse_with(Punctuated::<WherePredicate, Token![,]>::parse_terminated)?
+                    }
+                    lit => return Err(syn::Error::new_spanned(lit, "expected a string literal")),
+                };
+                bounds.get_or_insert_with(Vec::new).extend(predicates);
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_bound") => {
+                bounds.get_or_insert_with(Vec::new);
+            }
+            _ => {}
+        }
+    }
+    Ok(bounds)
+}
+
+// Struct-level `#[debug(...)]` flags.
+#[derive(Default)]
+struct StructOptions {
+    // Always print on one line, even under `{:#?}`.
+    compact: bool,
+    // Print exactly as the only field does.
+    transparent: bool,
+    // Name of the inherent method generated in place of the Debug impl.
+    impl_name: Option<Ident>,
+}
+
+fn collect_struct_options(attrs: &[Attribute]) -> syn::Result<StructOptions> {
+    let mut options = StructOptions::default();
+    for nested in struct_options(attrs)? {
+        match &nested {
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("bound") => {}
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("no_bound") => {}
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("compact") => {
+                options.compact = true;
+            }
+            NestedMeta::Meta(Meta::Path(path)) if path.is_ident("transparent") => {
+                options.transparent = true;
+            }
+            NestedMeta::Meta(Meta::NameValue(nv)) if nv.path.is_ident("impl_name") => {
+                options.impl_name = match &nv.lit {
+                    Lit::Str(s) => Some(s.parse()?),
+                    lit => return Err(syn::Error::new_spanned(lit, "expected a string literal")),
+                };
+            }
+            _ => return Err(syn::Error::new_spanned(nested, "unknown debug option")),
+        }
+    }
+    Ok(options)
+}
+
+// The items of every struct-level `#[debug(...)]` list.
+fn struct_options(attrs: &[Attribute]) -> syn::Result<Vec<NestedMeta>> {
+    let mut nested = Vec::new();
+    for attr in debug_attrs(attrs) {
+        if let Meta::List(list) = attr.parse_meta()? {
+            nested.extend(list.nested);
+        }
+    }
+    Ok(nested)
+}
+
+// One statement adding each field to the `debug` builder, made conditional
+// for fields with `skip_if`.
+fn format_debug_fields(
+    fields: &FieldsNamed,
+    formats: &FieldMap<FieldFormat>,
+    options: &FieldMap<FieldOptions>,
+) -> syn::Result<Vec<TokenStream2>> {
+    fields
+        .named
+        .iter()
+        .map(|field| {
+            let ident = field.ident.as_ref().unwrap();
+            let call = field_call(ident, formats, options)?;
+            Ok(match &options[ident].skip_if {
+                Some(predicate) => {
+                    let value = field_value(ident, options);
+                    quote! {
+                        if !(#predicate)(&#value) {
+                            debug #call;
+                        }
+                    }
+                }
+                None => quote! { debug #call; },
+            })
+        })
+        .collect()
+}
+
+// The `.field(...)` call printing a field.
+fn field_call(
+    ident: &Ident,
+    formats: &FieldMap<FieldFormat>,
+    options: &FieldMap<FieldOptions>,
+) -> syn::Result<TokenStream2> {
+    let name = match &options[ident].rename {
+        Some(rename) => rename.clone(),
+        None => ident.to_string(),
+    };
+    let value = field_value(ident, options);
+    if options[ident].redact {
+        if let Some(format) = formats.get(ident) {
+            return Err(syn::Error::new_spanned(
+                &format.lit,
+                "a field format cannot be combined with #[debug(redact)]",
+            ));
+        }
+        return Ok(quote! { .field(#name, &format_args!("[redacted]")) });
+    }
+    match (formats.get(ident), options[ident].display) {
+        (Some(format), true) => Err(syn::Error::new_spanned(
+            &format.lit,
+            "a field format cannot be combined with #[debug(display)]",
+        )),
+        (Some(FieldFormat { lit, args }), false) => {
+            let args = args.iter().map(|arg| field_value(arg, options));
+            Ok(quote! { .field(#name, &format_args!(#lit, #(&#args),*)) })
+        }
+        (None, true) => Ok(quote! { .field(#name, &format_args!("{}", &#value)) }),
+        (None, false) => Ok(quote! { .field(#name, &#value) }),
+    }
+}
+
+// How the body reads a field: directly, or through its getter.
+fn field_value(ident: &Ident, options: &FieldMap<FieldOptions>) -> TokenStream2 {
+    if options[ident].getter {
+        quote! { self.#ident() }
+    } else {
+        quote! { self.#ident }
+    }
+}
+
+// Expands a struct-level template into a `write!` whose named arguments are
+// the fields it mentions.
+fn format_debug_template(fields: &FieldsNamed, template: &LitStr) -> syn::Result<TokenStream2> {
+    let value = template.value();
+    let mut args = Vec::new();
+    let mut chars = value.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '{' if chars.peek() == Some(&'{') => {
+                chars.next();
+            }
+            '{' => {
+                let name: String = chars
+                    .by_ref()
+                    .take_while(|c| *c != '}' && *c != ':')
+                    .collect();
+                let field = fields
+                    .named
+                    .iter()
+                    .filter_map(|f| f.ident.as_ref())
+                    .find(|ident| *ident == name.trim())
+                    .ok_or_else(|| {
+                        syn::Error::new_spanned(
+                            template,
+                            format!(
+                                "`{}` in the format is not a field of this struct",
+                                name.trim()
+                            ),
+                        )
+                    })?;
+                if !args.contains(field) {
+                    args.push(field.clone());
+                }
+            }
+            _ => {}
+        }
+    }
+    Ok(quote! {
+        write!(f, #template, #(#args = &self.#args),*)
+    })
+}
+
+// Whether the type parameter `param` occurs anywhere in `ty`.
+fn mentions_param(ty: &Type, param: &Ident) -> bool {
+    mentions_param_tokens(quote!(#ty), param)
+}
+
+fn mentions_param_tokens(tokens: TokenStream2, param: &Ident) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(ident) => ident == *param,
+        TokenTree::Group(group) => mentions_param_tokens(group.stream(), param),
+        _ => false,
+    })
+}
+
+// Whether `param` occurs in `ty` other than inside a `PhantomData<...>`, which
+// is Debug whatever its argument, or at the root of an associated type like
+// `T::Value`, which is bounded on its own. Types that are not looked through,
+// such as trait objects, count as a use if they mention `param` at all.
+fn mentions_param_outside_phantom(ty: &Type, param: &Ident) -> bool {
+    match ty {
+        Type::Path(t)
+            if t.qself.is_none()
+                && t.path.segments.len() > 1
+                && t.path.segments[0].ident == *param =>
+        {
+            false
+        }
+        Type::Path(t) if t.qself.is_none() => t.path.segments.iter().any(|segment| {
+            if segment.ident == "PhantomData" {
+                return false;
+            }
+            if segment.ident == *param {
+                return true;
+            }
+            match &segment.arguments {
+                PathArguments::AngleBracketed(args) => args.args.iter().any(|arg| match arg {
+                    GenericArgument::Type(inner) => mentions_param_outside_phantom(inner, param),
+                    arg => mentions_param_tokens(quote!(#arg), param),
+                }),
+                arguments => mentions_param_tokens(quote!(#arguments), param),
+            }
+        }),
+        Type::Reference(r) => mentions_param_outside_phantom(&r.elem, param),
+        Type::Ptr(p) => mentions_param_outside_phantom(&p.elem, param),
+        Type::Slice(s) => mentions_param_outside_phantom(&s.elem, param),
+        Type::Array(a) => mentions_param_outside_phantom(&a.elem, param),
+        Type::Paren(p) => mentions_param_outside_phantom(&p.elem, param),
+        Type::Group(g) => mentions_param_outside_phantom(&g.elem, param),
+        Type::Tuple(t) => t
+            .elems
+            .iter()
+            .any(|elem| mentions_param_outside_phantom(elem, param)),
+        _ => mentions_param(ty, param),
+    }
+}
+
+// Paths like `T::Value` rooted at one of `params`.
+fn collect_associated_types(fields: &[&Field], params: &[Ident]) -> Vec<TypePath> {
+    let params: Vec<&Ident> = params.iter().collect();
+    let mut found = Vec::new();
+    for field in fields {
+        walk_associated_types(&field.ty, &params, &mut found);
+    }
+    found
+}
 
-    unimplemented!()
+// References, pointers, slices, arrays and tuples are looked through, so
+// `&'a [T::Value]` still finds `T::Value`. Lifetime arguments are skipped.
+fn walk_associated_types(ty: &Type, params: &[&Ident], found: &mut Vec<TypePath>) {
+    match ty {
+        Type::Path(t) => {
+            if t.qself.is_none()
+                && t.path.segments.len() > 1
+                && params.contains(&&t.path.segments[0].ident)
+            {
+                if !found.contains(t) {
+                    found.push(t.clone());
+                }
+                return;
+            }
+            for segment in &t.path.segments {
+                if let PathArguments::AngleBracketed(args) = &segment.arguments {
+                    for arg in &args.args {
+                        if let GenericArgument::Type(inner) = arg {
+                            walk_associated_types(inner, params, found);
+                        }
+                    }
+                }
+            }
+        }
+        Type::Reference(r) => walk_associated_types(&r.elem, params, found),
+        Type::Ptr(p) => walk_associated_types(&p.elem, params, found),
+        Type::Slice(s) => walk_associated_types(&s.elem, params, found),
+        Type::Array(a) => walk_associated_types(&a.elem, params, found),
+        Type::Paren(p) => walk_associated_types(&p.elem, params, found),
+        Type::Group(g) => walk_associated_types(&g.elem, params, found),
+        Type::Tuple(t) => {
+            for elem in &t.elems {
+                walk_associated_types(elem, params, found);
+            }
+        }
+        _ => {}
+    }
 }
@@ -0,0 +1,34 @@
+// A `#[debug = "..."]` attribute on the struct itself replaces the
+// field-by-field `debug_struct` output with a single custom format. Named
+// placeholders in the format refer to the struct's fields.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug = "Point({x}, {y})"]
+pub struct Point {
+    x: i32,
+    y: i32,
+}
+
+#[derive(CustomDebug)]
+#[debug = "{name:?}@{{{version:>4}}}"]
+pub struct Package<T> {
+    name: T,
+    version: u32,
+    checksum: u64,
+}
+
+fn main() {
+    let p = Point { x: 1, y: -2 };
+    assert_eq!(format!("{:?}", p), "Point(1, -2)");
+    assert_eq!(format!("{:#?}", p), "Point(1, -2)");
+
+    let pkg = Package {
+        name: "syn",
+        version: 1,
+        checksum: 0,
+    };
+    assert_eq!(format!("{:?}", pkg), "\"syn\"@{   1}");
+    let _ = pkg.checksum;
+}
@@ -0,0 +1,14 @@
+// A struct-level format takes over the whole output, so a per-field format
+// alongside it would silently do nothing. Reject the combination instead.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug = "Point({x}, {y})"]
+pub struct Point {
+    #[debug = "{:x}"]
+    x: i32,
+    y: i32,
+}
+
+fn main() {}
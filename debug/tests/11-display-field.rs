@@ -0,0 +1,51 @@
+// A field marked `#[debug(display)]` is printed through its Display impl
+// instead of Debug. Such a field only needs to implement Display, so a type
+// parameter used solely by display fields gets a Display bound rather than a
+// Debug bound.
+
+use derive_debug::CustomDebug;
+use std::fmt::{self, Debug, Display};
+
+// Implements Display but not Debug.
+pub struct Version(u32, u32);
+
+impl Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "v{}.{}", self.0, self.1)
+    }
+}
+
+#[derive(CustomDebug)]
+pub struct Release {
+    name: &'static str,
+    #[debug(display)]
+    version: Version,
+}
+
+#[derive(CustomDebug)]
+pub struct Labeled<T, U> {
+    #[debug(display)]
+    label: T,
+    value: U,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    let release = Release {
+        name: "syn",
+        version: Version(1, 0),
+    };
+    assert_eq!(
+        format!("{:?}", release),
+        r#"Release { name: "syn", version: v1.0 }"#,
+    );
+
+    let labeled = Labeled {
+        label: Version(2, 3),
+        value: 'x',
+    };
+    assert_eq!(format!("{:?}", labeled), "Labeled { label: v2.3, value: 'x' }");
+
+    assert_debug::<Labeled<Version, u8>>();
+}
@@ -0,0 +1,54 @@
+// Fields behind shared references are printed as their pointee, and the
+// bound inference looks through the reference: `&'a T` requires `T: Debug`
+// and `&'a [T::Value]` requires `T::Value: Debug`, with the lifetime passed
+// through untouched.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+pub trait Trait {
+    type Value;
+}
+
+#[derive(CustomDebug)]
+pub struct Holder<'a, T> {
+    value: &'a T,
+    name: &'a str,
+}
+
+#[derive(CustomDebug)]
+pub struct Values<'a, T: Trait> {
+    values: &'a [T::Value],
+    pair: (&'a T::Value, u8),
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    let n = 5u8;
+    let holder = Holder {
+        value: &n,
+        name: "five",
+    };
+    assert_eq!(
+        format!("{:?}", holder),
+        r#"Holder { value: 5, name: "five" }"#,
+    );
+
+    // Does not implement Debug, but its associated type does.
+    struct Id;
+
+    impl Trait for Id {
+        type Value = u8;
+    }
+
+    let values = Values::<Id> {
+        values: &[1, 2],
+        pair: (&3, 4),
+    };
+    assert_eq!(
+        format!("{:?}", values),
+        "Values { values: [1, 2], pair: (3, 4) }",
+    );
+    assert_debug::<Values<Id>>();
+}
@@ -0,0 +1,34 @@
+// Const generic parameters are threaded through the impl's generics like any
+// other parameter but never receive a Debug bound; only type parameters do.
+// Defaults on const parameters are left off the impl.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+#[derive(CustomDebug)]
+pub struct Buf<const N: usize> {
+    data: [u8; N],
+}
+
+#[derive(CustomDebug)]
+pub struct Mixed<T, const N: usize = 2> {
+    items: [T; N],
+    #[debug = "{}"]
+    len: usize,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    let buf = Buf { data: [1, 2, 3] };
+    assert_eq!(format!("{:?}", buf), "Buf { data: [1, 2, 3] }");
+
+    let mixed: Mixed<char> = Mixed {
+        items: ['a', 'b'],
+        len: 2,
+    };
+    assert_eq!(format!("{:?}", mixed), "Mixed { items: ['a', 'b'], len: 2 }");
+
+    assert_debug::<Buf<0>>();
+    assert_debug::<Mixed<u8, 16>>();
+}
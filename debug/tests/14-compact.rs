@@ -0,0 +1,36 @@
+// A struct-level `#[debug(compact)]` keeps the output on a single line even
+// when formatted with `{:#?}`, including any nested values.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Pretty {
+    id: u32,
+    tags: Vec<&'static str>,
+}
+
+#[derive(CustomDebug)]
+#[debug(compact)]
+pub struct Compact {
+    id: u32,
+    tags: Vec<&'static str>,
+}
+
+fn main() {
+    let pretty = Pretty {
+        id: 1,
+        tags: vec!["a"],
+    };
+    assert_eq!(
+        format!("{:#?}", pretty),
+        "Pretty {\n    id: 1,\n    tags: [\n        \"a\",\n    ],\n}",
+    );
+
+    let compact = Compact {
+        id: 1,
+        tags: vec!["a"],
+    };
+    let expected = r#"Compact { id: 1, tags: ["a"] }"#;
+    assert_eq!(format!("{:?}", compact), expected);
+    assert_eq!(format!("{:#?}", compact), expected);
+}
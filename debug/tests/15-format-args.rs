@@ -0,0 +1,32 @@
+// Besides `#[debug = "..."]`, which formats the field itself, a field format
+// can be written as a list whose first element is the format string and the
+// rest name fields of the struct to pass as its arguments:
+//
+//     #[debug("{}.{}", major, minor)]
+//
+// The field keeps its own name as the printed key.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Version {
+    #[debug("{}.{}.{}", major, minor, patch)]
+    version: (),
+    major: u32,
+    minor: u32,
+    #[debug = "#{}"]
+    patch: u32,
+}
+
+fn main() {
+    let v = Version {
+        version: (),
+        major: 1,
+        minor: 2,
+        patch: 3,
+    };
+    assert_eq!(
+        format!("{:?}", v),
+        "Version { version: 1.2.3, major: 1, minor: 2, patch: #3 }",
+    );
+}
@@ -0,0 +1,13 @@
+// Arguments of a list-form format must name fields of the struct.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Version {
+    #[debug("{}.{}", major, minr)]
+    version: (),
+    major: u32,
+    minor: u32,
+}
+
+fn main() {}
@@ -0,0 +1,39 @@
+// A struct-level `#[debug(transparent)]` on a struct with exactly one field
+// prints that field exactly as its own Debug impl would, as if the wrapper
+// were not there. Both tuple and named newtypes are accepted.
+//
+// The field's type is still required to implement Debug:
+//
+//     impl<T> Debug for Meters<T> where T: Debug { ... }
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(transparent)]
+pub struct Meters<T>(T);
+
+#[derive(CustomDebug)]
+#[debug(transparent)]
+pub struct UserId {
+    raw: String,
+}
+
+#[derive(CustomDebug)]
+pub struct Record {
+    id: UserId,
+    length: Meters<f64>,
+}
+
+fn main() {
+    let record = Record {
+        id: UserId {
+            raw: "u-1".to_owned(),
+        },
+        length: Meters(1.5),
+    };
+    assert_eq!(
+        format!("{:?}", record),
+        r#"Record { id: "u-1", length: 1.5 }"#,
+    );
+    assert_eq!(format!("{:#?}", Meters(vec![1])), "[\n    1,\n]");
+}
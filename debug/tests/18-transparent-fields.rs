@@ -0,0 +1,10 @@
+// `#[debug(transparent)]` only makes sense when there is a single field to
+// delegate to.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(transparent)]
+pub struct Pair(u8, u8);
+
+fn main() {}
@@ -0,0 +1,13 @@
+// Unions have no way of telling which field is active, so there is nothing
+// safe to print. Deriving CustomDebug for one is a compile error rather than a
+// panic inside the macro.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub union Bits {
+    int: u32,
+    float: f32,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+// Enums are not supported yet. Deriving CustomDebug for one reports a compile
+// error at the enum's name instead of panicking inside the macro.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+fn main() {}
@@ -0,0 +1,41 @@
+// PhantomData is Debug whatever its type argument, so a type parameter that
+// only appears somewhere inside a `PhantomData<...>` gets no Debug bound, even
+// when it is nested in a pointer, function or container type there.
+//
+// A parameter that is also used outside of PhantomData is still bounded.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+use std::marker::PhantomData;
+
+#[derive(CustomDebug)]
+pub struct Handle<T, U, V> {
+    id: u32,
+    factory: PhantomData<fn() -> T>,
+    raw: PhantomData<*const U>,
+    owned: std::marker::PhantomData<Box<V>>,
+}
+
+#[derive(CustomDebug)]
+pub struct Tagged<T> {
+    value: T,
+    tag: PhantomData<fn(T)>,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    struct NotDebug;
+
+    assert_debug::<Handle<NotDebug, NotDebug, NotDebug>>();
+    assert_debug::<Tagged<u8>>();
+
+    let tagged = Tagged {
+        value: 7u8,
+        tag: PhantomData,
+    };
+    assert_eq!(
+        format!("{:?}", tagged),
+        "Tagged { value: 7, tag: PhantomData<fn(u8)> }",
+    );
+}
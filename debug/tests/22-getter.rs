@@ -0,0 +1,46 @@
+// A field marked `#[debug(getter)]` is printed through a method of the same
+// name, `self.field()`, instead of being read directly. This is useful when the
+// stored representation differs from the value worth showing.
+//
+// The getter's result is printed as usual, so it combines with a field format
+// or with `#[debug(display)]`.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Temperature {
+    #[debug(getter)]
+    celsius: i32,
+    #[debug(getter)]
+    #[debug = "{:.1}"]
+    fahrenheit: (),
+    #[debug(getter, display)]
+    label: &'static str,
+}
+
+impl Temperature {
+    // Stored in tenths of a degree.
+    fn celsius(&self) -> i32 {
+        self.celsius / 10
+    }
+
+    fn fahrenheit(&self) -> f64 {
+        self.celsius as f64 / 10.0 * 9.0 / 5.0 + 32.0
+    }
+
+    fn label(&self) -> String {
+        self.label.to_uppercase()
+    }
+}
+
+fn main() {
+    let t = Temperature {
+        celsius: 215,
+        fahrenheit: (),
+        label: "warm",
+    };
+    assert_eq!(
+        format!("{:?}", t),
+        "Temperature { celsius: 21, fahrenheit: 70.7, label: WARM }",
+    );
+}
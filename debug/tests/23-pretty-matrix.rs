@@ -0,0 +1,46 @@
+// The alternate flag of `{:#?}` reaches every field printed without a custom
+// format, so nested values are pretty-printed along with the struct. A field
+// format is applied as written: `{:#?}` in it pretty-prints that field even if
+// the struct itself is printed with `{:?}`, and `{:?}` keeps it on one line
+// even under `{:#?}`.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Plain {
+    id: u8,
+    tags: Vec<u8>,
+}
+
+#[derive(CustomDebug)]
+pub struct Formatted {
+    #[debug = "{:#?}"]
+    pretty: Vec<u8>,
+    #[debug = "{:?}"]
+    flat: Vec<u8>,
+}
+
+fn main() {
+    let plain = Plain {
+        id: 1,
+        tags: vec![2, 3],
+    };
+    assert_eq!(format!("{:?}", plain), "Plain { id: 1, tags: [2, 3] }");
+    assert_eq!(
+        format!("{:#?}", plain),
+        "Plain {\n    id: 1,\n    tags: [\n        2,\n        3,\n    ],\n}",
+    );
+
+    let formatted = Formatted {
+        pretty: vec![4],
+        flat: vec![5, 6],
+    };
+    assert_eq!(
+        format!("{:?}", formatted),
+        "Formatted { pretty: [\n    4,\n], flat: [5, 6] }",
+    );
+    assert_eq!(
+        format!("{:#?}", formatted),
+        "Formatted {\n    pretty: [\n        4,\n    ],\n    flat: [5, 6],\n}",
+    );
+}
@@ -0,0 +1,41 @@
+// Bounds the struct already declares are kept as written, and the inferred
+// bounds are added after them. An inferred bound the struct has already spelled
+// out, on the parameter or in the where clause, is not repeated:
+//
+//     impl<T: Clone, U: Iterator + Debug> Debug for Merged<T, U>
+//     where
+//         T: Debug,
+//         U::Item: std::fmt::Debug,
+//         U: Clone,
+//     { ... }
+//
+// A parameter such as `U` that is used directly as well as through an
+// associated type needs a bound of its own.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+#[derive(CustomDebug)]
+pub struct Merged<T: Clone, U: Iterator>
+where
+    T: Debug,
+    U::Item: std::fmt::Debug,
+    U: Clone,
+{
+    value: T,
+    items: Vec<U::Item>,
+    source: U,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    assert_debug::<Merged<u8, std::vec::IntoIter<char>>>();
+
+    let merged = Merged {
+        value: 1u8,
+        items: vec!['a'],
+        source: vec!['b'].into_iter(),
+    };
+    assert!(format!("{:?}", merged).starts_with("Merged { value: 1, items: ['a'], source: IntoIter("));
+}
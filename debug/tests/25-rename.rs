@@ -0,0 +1,27 @@
+// `#[debug(rename = "...")]` changes the name printed for a field, while the
+// value is still read from the field itself. It composes with a field format:
+// the rename sets the key, the format sets the value.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Session {
+    #[debug(rename = "user_id")]
+    uid: u64,
+    #[debug(rename = "flags")]
+    #[debug = "0b{:04b}"]
+    bits: u8,
+    active: bool,
+}
+
+fn main() {
+    let session = Session {
+        uid: 42,
+        bits: 5,
+        active: true,
+    };
+    assert_eq!(
+        format!("{:?}", session),
+        "Session { user_id: 42, flags: 0b0101, active: true }",
+    );
+}
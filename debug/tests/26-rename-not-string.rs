@@ -0,0 +1,11 @@
+// The new name given to `#[debug(rename = ...)]` must be a string literal.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Session {
+    #[debug(rename = 7)]
+    uid: u64,
+}
+
+fn main() {}
@@ -0,0 +1,34 @@
+// `#[debug(impl_name = "...")]` generates an inherent method of that name,
+// taking the formatter like `Debug::fmt`, instead of the Debug impl. A type
+// that already implements Debug, by hand or with `#[derive(Debug)]`, can then
+// still have the custom format, for example to use in another impl.
+//
+//     impl Point {
+//         pub fn fmt_custom(&self, f: &mut fmt::Formatter) -> fmt::Result {
+//             ...
+//         }
+//     }
+
+use derive_debug::CustomDebug;
+use std::fmt;
+
+#[derive(Debug, CustomDebug)]
+#[debug(impl_name = "fmt_custom")]
+pub struct Point {
+    #[debug = "{:.1}"]
+    x: f64,
+    #[debug = "{:.1}"]
+    y: f64,
+}
+
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.fmt_custom(f)
+    }
+}
+
+fn main() {
+    let point = Point { x: 1.25, y: 2.0 };
+    assert_eq!(format!("{:?}", point), "Point { x: 1.25, y: 2.0 }");
+    assert_eq!(format!("{}", point), "Point { x: 1.2, y: 2.0 }");
+}
@@ -0,0 +1,60 @@
+// Lifetime parameters, including their bounds on one another and on type
+// parameters, are passed through to the impl as declared. Only the type
+// parameter is given a Debug bound.
+//
+// The impl for `Pair` is expected to be:
+//
+//     impl<'a, 'b: 'a, T: 'b + Debug> Debug for Pair<'a, 'b, T> { ... }
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+#[derive(CustomDebug)]
+pub struct Ref<'a> {
+    name: &'a str,
+}
+
+#[derive(CustomDebug)]
+pub struct Pair<'a, 'b: 'a, T: 'b> {
+    first: &'a T,
+    rest: &'b [T],
+    tag: &'static str,
+}
+
+#[derive(CustomDebug)]
+pub struct Nested<'a, T> {
+    inner: Ref<'a>,
+    pair: Pair<'a, 'a, T>,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    let name = String::from("first");
+    let r = Ref { name: &name };
+    assert_eq!(format!("{:?}", r), r#"Ref { name: "first" }"#);
+
+    let numbers = [1u8, 2, 3];
+    let pair = Pair {
+        first: &numbers[0],
+        rest: &numbers[1..],
+        tag: "numbers",
+    };
+    assert_eq!(
+        format!("{:?}", pair),
+        r#"Pair { first: 1, rest: [2, 3], tag: "numbers" }"#,
+    );
+
+    let nested = Nested {
+        inner: Ref { name: "inner" },
+        pair,
+    };
+    assert_eq!(
+        format!("{:?}", nested),
+        r#"Nested { inner: Ref { name: "inner" }, pair: Pair { first: 1, rest: [2, 3], tag: "numbers" } }"#,
+    );
+
+    assert_debug::<Ref<'static>>();
+    assert_debug::<Pair<'static, 'static, String>>();
+    assert_debug::<Nested<'static, u8>>();
+}
@@ -0,0 +1,79 @@
+// `#[debug(no_bound)]`, or equivalently `#[debug(bound = "")]`, turns off
+// bound inference without adding any predicate, for fields whose Debug impl
+// does not depend on the type parameters at all. Several `bound` entries, and
+// several comma-separated predicates within one, are all added:
+//
+//     impl<K: Trait, V: Trait> Debug for Table<K, V>
+//     where
+//         K::Value: Debug,
+//         V::Value: Debug,
+//     {...}
+
+use derive_debug::CustomDebug;
+use std::fmt::{self, Debug};
+use std::marker::PhantomData;
+
+pub trait Trait {
+    type Value;
+}
+
+// Prints the same whatever `T` is.
+pub struct Handle<T>(u32, PhantomData<fn(T)>);
+
+impl<T> Debug for Handle<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{}", self.0)
+    }
+}
+
+#[derive(CustomDebug)]
+#[debug(no_bound)]
+pub struct Registry<T> {
+    handle: Handle<T>,
+}
+
+#[derive(CustomDebug)]
+#[debug(bound = "")]
+pub struct Slot<T> {
+    handle: Handle<T>,
+    index: usize,
+}
+
+#[derive(CustomDebug)]
+#[debug(bound = "K::Value: Debug", bound = "V::Value: Debug")]
+pub struct Table<K: Trait, V: Trait> {
+    keys: Vec<K::Value>,
+    values: Vec<V::Value>,
+}
+
+#[derive(CustomDebug)]
+#[debug(bound = "K::Value: Debug, V::Value: Debug")]
+pub struct Pair<K: Trait, V: Trait> {
+    key: K::Value,
+    value: V::Value,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    // Does not implement Debug.
+    struct Opaque;
+
+    impl Trait for Opaque {
+        type Value = u8;
+    }
+
+    let registry = Registry::<Opaque> {
+        handle: Handle(3, PhantomData),
+    };
+    assert_eq!(format!("{:?}", registry), "Registry { handle: #3 }");
+    assert_debug::<Registry<Opaque>>();
+    assert_debug::<Slot<Opaque>>();
+
+    let table = Table::<Opaque, Opaque> {
+        keys: vec![1],
+        values: vec![2],
+    };
+    assert_eq!(format!("{:?}", table), "Table { keys: [1], values: [2] }");
+    assert_debug::<Pair<Opaque, Opaque>>();
+}
@@ -0,0 +1,27 @@
+// A field format can also be written as `#[debug(format = "...")]`, which
+// means the same as `#[debug = "..."]` and fits in one list with the field's
+// other options.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Register {
+    #[debug(format = "0x{:08x}")]
+    value: u32,
+    #[debug(rename = "flags", format = "0b{:04b}")]
+    bits: u8,
+    #[debug = "{:>3}"]
+    index: u8,
+}
+
+fn main() {
+    let register = Register {
+        value: 0xbeef,
+        bits: 5,
+        index: 7,
+    };
+    assert_eq!(
+        format!("{:?}", register),
+        "Register { value: 0x0000beef, flags: 0b0101, index:   7 }",
+    );
+}
@@ -0,0 +1,11 @@
+// A misspelled field option is an error listing the options there are.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Register {
+    #[debug(fromat = "0x{:08x}")]
+    value: u32,
+}
+
+fn main() {}
@@ -0,0 +1,36 @@
+// `#[debug(redact)]` prints a placeholder in place of the field's value. The
+// value is never read, so its type does not have to implement Debug and a type
+// parameter only used by redacted fields gets no bound.
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+// Does not implement Debug.
+pub struct Secret(String);
+
+#[derive(CustomDebug)]
+pub struct Credentials<K> {
+    user: String,
+    #[debug(redact)]
+    password: Secret,
+    #[debug(redact, rename = "key")]
+    api_key: K,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    let credentials = Credentials {
+        user: "ferris".to_owned(),
+        password: Secret("hunter2".to_owned()),
+        api_key: Secret("sk-1234".to_owned()),
+    };
+    let debug = format!("{:?}", credentials);
+    assert_eq!(
+        debug,
+        r#"Credentials { user: "ferris", password: [redacted], key: [redacted] }"#,
+    );
+    assert!(!debug.contains("hunter2"));
+    assert!(!format!("{:#?}", credentials).contains("sk-1234"));
+    assert_debug::<Credentials<Secret>>();
+}
@@ -0,0 +1,80 @@
+// A type parameter used directly gets a `T: Debug` bound and one used through
+// an associated type gets `T::Value: Debug`. Used both ways, it gets both:
+//
+//     impl<T: Trait + Debug> Debug for Both<T>
+//     where
+//         T::Value: Debug,
+//     {...}
+//
+// A custom bound only replaces inference for the type parameters it mentions.
+// Here `K` is covered by the custom bound and `U` is still inferred:
+//
+//     impl<K: Trait, U: Debug> Debug for Mixed<K, U>
+//     where
+//         K::Value: Debug,
+//     {...}
+
+use derive_debug::CustomDebug;
+use std::fmt::Debug;
+
+pub trait Trait {
+    type Value;
+}
+
+#[derive(CustomDebug)]
+pub struct Both<T: Trait> {
+    value: T,
+    values: Vec<T::Value>,
+    first: Option<T::Value>,
+}
+
+#[derive(CustomDebug)]
+#[debug(bound = "K::Value: Debug")]
+pub struct Mixed<K: Trait, U> {
+    key: Field<K>,
+    other: U,
+}
+
+#[derive(CustomDebug)]
+pub struct Field<K: Trait> {
+    values: Vec<K::Value>,
+}
+
+fn assert_debug<F: Debug>() {}
+
+fn main() {
+    #[derive(Debug)]
+    struct Id;
+
+    impl Trait for Id {
+        type Value = u8;
+    }
+
+    // Does not implement Debug.
+    struct Opaque;
+
+    impl Trait for Opaque {
+        type Value = u8;
+    }
+
+    let both = Both {
+        value: Id,
+        values: vec![1, 2],
+        first: Some(1),
+    };
+    assert_eq!(
+        format!("{:?}", both),
+        "Both { value: Id, values: [1, 2], first: Some(1) }",
+    );
+
+    let mixed = Mixed::<Opaque, &str> {
+        key: Field { values: vec![3] },
+        other: "other",
+    };
+    assert_eq!(
+        format!("{:?}", mixed),
+        r#"Mixed { key: Field { values: [3] }, other: "other" }"#,
+    );
+    assert_debug::<Both<Id>>();
+    assert_debug::<Mixed<Opaque, u8>>();
+}
@@ -0,0 +1,68 @@
+// Fields are printed in the order they are declared, whatever mix of options
+// they carry, including under `{:#?}`. The expansion is the same on every
+// build, so its output can be compared against a fixed snapshot.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Settings {
+    zeta: u8,
+    #[debug = "0x{:02x}"]
+    alpha: u8,
+    #[debug(rename = "m")]
+    mu: u8,
+    #[debug(display)]
+    beta: &'static str,
+    omega: bool,
+    #[debug(redact)]
+    gamma: u64,
+    delta: Option<u8>,
+    #[debug(format = "{:?}")]
+    epsilon: char,
+    eta: (),
+    #[debug(rename = "a")]
+    theta: i32,
+    iota: [u8; 2],
+    kappa: u16,
+}
+
+fn main() {
+    let settings = Settings {
+        zeta: 26,
+        alpha: 1,
+        mu: 12,
+        beta: "two",
+        omega: true,
+        gamma: 3,
+        delta: Some(4),
+        epsilon: 'e',
+        eta: (),
+        theta: -8,
+        iota: [9, 10],
+        kappa: 11,
+    };
+
+    let expected = "Settings { zeta: 26, alpha: 0x01, m: 12, beta: two, omega: true, \
+                    gamma: [redacted], delta: Some(4), epsilon: 'e', eta: (), a: -8, \
+                    iota: [9, 10], kappa: 11 }";
+    for _ in 0..3 {
+        assert_eq!(format!("{:?}", settings), expected);
+    }
+
+    let pretty = format!("{:#?}", settings);
+    let names: Vec<&str> = pretty
+        .lines()
+        .skip(1)
+        .filter(|line| {
+            line.starts_with("    ") && !line.starts_with("     ") && line.contains(": ")
+        })
+        .map(|line| line.trim_start().split(':').next().unwrap())
+        .collect();
+    assert_eq!(
+        names,
+        [
+            "zeta", "alpha", "m", "beta", "omega", "gamma", "delta", "epsilon", "eta", "a", "iota",
+            "kappa",
+        ],
+    );
+}
@@ -0,0 +1,46 @@
+// `#[debug(skip_if = "...")]` leaves a field out of the output whenever the
+// predicate, given a reference to the field's value, returns true. It takes
+// any expression that can be called that way, such as a path to a function
+// or a closure, so noisy fields holding nothing of interest can be hidden
+// without hiding them when they do.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+pub struct Request {
+    method: &'static str,
+    #[debug(skip_if = "Option::is_none")]
+    body: Option<String>,
+    #[debug(skip_if = "Option::is_none")]
+    timeout: Option<u32>,
+    #[debug(skip_if = "Vec::is_empty")]
+    headers: Vec<&'static str>,
+    #[debug(skip_if = "|retries: &u8| *retries == 0", rename = "tries")]
+    retries: u8,
+}
+
+fn main() {
+    let request = Request {
+        method: "GET",
+        body: None,
+        timeout: Some(30),
+        headers: Vec::new(),
+        retries: 0,
+    };
+    assert_eq!(
+        format!("{:?}", request),
+        r#"Request { method: "GET", timeout: Some(30) }"#,
+    );
+
+    let request = Request {
+        method: "POST",
+        body: Some("{}".to_owned()),
+        timeout: None,
+        headers: vec!["Accept"],
+        retries: 2,
+    };
+    assert_eq!(
+        format!("{:?}", request),
+        r#"Request { method: "POST", body: Some("{}"), headers: ["Accept"], tries: 2 }"#,
+    );
+}
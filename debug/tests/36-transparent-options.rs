@@ -0,0 +1,15 @@
+// A `#[debug(transparent)]` struct prints exactly as its field does, so a
+// struct format or `compact` alongside it would be silently ignored.
+
+use derive_debug::CustomDebug;
+
+#[derive(CustomDebug)]
+#[debug(transparent, compact)]
+pub struct Ids(Vec<u32>);
+
+#[derive(CustomDebug)]
+#[debug(transparent)]
+#[debug = "Name({})"]
+pub struct Name(String);
+
+fn main() {}
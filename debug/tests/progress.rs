@@ -1,12 +1,40 @@
 #[test]
 fn tests() {
     let t = trybuild::TestCases::new();
-    //t.pass("tests/01-parse.rs");
-    //t.pass("tests/02-impl-debug.rs");
-    //t.pass("tests/03-custom-format.rs");
-    //t.pass("tests/04-type-parameter.rs");
-    //t.pass("tests/05-phantom-data.rs");
-    //t.pass("tests/06-bound-trouble.rs");
-    //t.pass("tests/07-associated-type.rs");
-    //t.pass("tests/08-escape-hatch.rs");
+    t.pass("tests/01-parse.rs");
+    t.pass("tests/02-impl-debug.rs");
+    t.pass("tests/03-custom-format.rs");
+    t.pass("tests/04-type-parameter.rs");
+    t.pass("tests/05-phantom-data.rs");
+    t.pass("tests/06-bound-trouble.rs");
+    t.pass("tests/07-associated-type.rs");
+    t.pass("tests/08-escape-hatch.rs");
+    t.pass("tests/09-struct-format.rs");
+    t.compile_fail("tests/10-struct-format-conflict.rs");
+    t.pass("tests/11-display-field.rs");
+    t.pass("tests/12-reference-field.rs");
+    t.pass("tests/13-const-generics.rs");
+    t.pass("tests/14-compact.rs");
+    t.pass("tests/15-format-args.rs");
+    t.compile_fail("tests/16-format-args-unknown.rs");
+    t.pass("tests/17-transparent.rs");
+    t.compile_fail("tests/18-transparent-fields.rs");
+    t.compile_fail("tests/19-union.rs");
+    t.compile_fail("tests/20-enum.rs");
+    t.pass("tests/21-phantom-nested.rs");
+    t.pass("tests/22-getter.rs");
+    t.pass("tests/23-pretty-matrix.rs");
+    t.pass("tests/24-existing-where-clause.rs");
+    t.pass("tests/25-rename.rs");
+    t.compile_fail("tests/26-rename-not-string.rs");
+    t.pass("tests/27-impl-name.rs");
+    t.pass("tests/28-lifetimes.rs");
+    t.pass("tests/29-no-bound.rs");
+    t.pass("tests/30-format-option.rs");
+    t.compile_fail("tests/31-unknown-field-option.rs");
+    t.pass("tests/32-redact.rs");
+    t.pass("tests/33-bare-and-associated.rs");
+    t.pass("tests/34-field-order.rs");
+    t.pass("tests/35-skip-if.rs");
+    t.compile_fail("tests/36-transparent-options.rs");
 }
@@ -1,10 +1,24 @@
 extern crate proc_macro;
 
+mod seq;
+
 use proc_macro::TokenStream;
 
 #[proc_macro]
 pub fn seq(input: TokenStream) -> TokenStream {
-    let _ = input;
+    expand(input.into())
+        .unwrap_or_else(|e| e.to_compile_error())
+        .into()
+}
 
-    unimplemented!()
+fn expand(input: proc_macro2::TokenStream) -> syn::Result<proc_macro2::TokenStream> {
+    let seq: seq::Seq = syn::parse2(input)?;
+    seq.expand()
+}
+
+// Kept for callers written against the proc-macro-hack era split between item
+// and expression position; both now expand the same way.
+#[proc_macro]
+pub fn eseq(input: TokenStream) -> TokenStream {
+    seq(input)
 }
@@ -0,0 +1,447 @@
+use proc_macro2::{Delimiter, Group, Ident, Literal, Punct, Spacing, Span, TokenStream, TokenTree};
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{braced, bracketed, token, LitInt, LitStr, Token};
+
+pub struct Seq {
+    var: Ident,
+    start: i128,
+    end: i128,
+    // Optional `names = ["a", "b"]` after the range, indexed by the loop
+    // variable in `~{names[N]}~`.
+    names: Option<Vec<String>>,
+    body: TokenStream,
+}
+
+// Bounds may be written in any radix (`0x10`, `0o20`, `0b1_0000`) and may be
+// negative; only their value is kept, so the loop variable is always
+// substituted as a decimal literal. The range is stored with an exclusive end,
+// which has to fit in a u64.
+//
+// A bound may carry an integer suffix, as in `0u32..8u32`, which has to fit
+// its value. Suffixes are not kept either, so `N` takes whatever type its
+// uses call for. Two bounds with different suffixes are an error.
+impl Parse for Seq {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        let var: Ident = input.parse()?;
+        input.parse::<Token![in]>()?;
+        let (start, start_lit) = parse_bound(input)?;
+        let inclusive = input.peek(Token![..=]);
+        if inclusive {
+            input.parse::<Token![..=]>()?;
+        } else {
+            input.parse::<Token![..]>()?;
+        }
+        let (end, end_lit) = parse_bound(input)?;
+        let (start_suffix, end_suffix) = (start_lit.suffix(), end_lit.suffix());
+        if !start_suffix.is_empty() && !end_suffix.is_empty() && start_suffix != end_suffix {
+            return Err(syn::Error::new_spanned(
+                &end_lit,
+                format!(
+                    "range bounds have different suffixes, `{}` and `{}`",
+                    start_suffix, end_suffix
+                ),
+            ));
+        }
+        let end = if inclusive { end + 1 } else { end };
+        if end > i128::from(u64::MAX) {
+            return Err(syn::Error::new_spanned(
+                &end_lit,
+                "inclusive range end is too large",
+            ));
+        }
+
+        let names = if input.peek(syn::Ident) {
+            Some(parse_names(input, start, end)?)
+        } else {
+            None
+        };
+
+        if !input.peek(token::Brace) {
+            return Err(input.error("expected `{ ... }` body after the range"));
+        }
+        let content;
+        braced!(content in input);
+        let body: TokenStream = content.parse()?;
+
+        Ok(Seq {
+            var,
+            start,
+            end,
+            names,
+            body,
+        })
+    }
+}
+
+impl Seq {
+    pub fn expand(&self) -> syn::Result<TokenStream> {
+        let (expanded, found) = self.expand_repetitions(self.body.clone())?;
+        if found {
+            return Ok(expanded);
+        }
+        let template = self.template(self.body.clone())?;
+        let mut out = Vec::new();
+        for n in self.start..self.end {
+            instantiate(&template, n, self.names.as_deref(), &mut out)?;
+        }
+        Ok(out.into_iter().collect())
+    }
+
+    // Repeats every `#(...)*` section found anywhere in `stream`, returning
+    // whether there was at least one. Tokens outside of those sections are
+    // left as written.
+    fn expand_repetitions(&self, stream: TokenStream) -> syn::Result<(TokenStream, bool)> {
+        let tokens: Vec<TokenTree> = stream.into_iter().collect();
+        let mut out = Vec::with_capacity(tokens.len());
+        let mut found = false;
+        let mut i = 0;
+        while i < tokens.len() {
+            if let Some(region) = repetition_at(&tokens, i) {
+                let template = self.template(region)?;
+                for n in self.start..self.end {
+                    instantiate(&template, n, self.names.as_deref(), &mut out)?;
+                }
+                found = true;
+                i += 3;
+                continue;
+            }
+            match &tokens[i] {
+                TokenTree::Group(group) => {
+                    let (inner, inner_found) = self.expand_repetitions(group.stream())?;
+                    found |= inner_found;
+                    out.push(TokenTree::Group(regroup(group, inner)));
+                }
+                tt => out.push(tt.clone()),
+            }
+            i += 1;
+        }
+        Ok((out.into_iter().collect(), found))
+    }
+
+    // Works out once where the loop variable is substituted and where it is
+    // pasted, so that each value only takes a single pass over the result.
+    // A body repeated a thousand times is scanned once, not a thousand times.
+    //
+    // The loop variable is reserved within the body: every identifier equal to
+    // it is replaced, whatever its position. Literals, including the contents
+    // of strings, are never looked into, and a lifetime named like the
+    // variable is left alone.
+    //
+    // Pasting onto a keyword or raw identifier gives a plain identifier, so
+    // `fn~N` and `r#fn~N` both become `fn0`. Anything other than an
+    // identifier before `~N` is an error, since the result would not be one.
+    // `~{names[N]}~` is replaced by the value's entry in the names list, and
+    // likewise pasted onto an identifier right before it.
+    //
+    // No tokens are made up with a span of their own: a pasted identifier
+    // keeps the span of its prefix and a number that of the variable, so the
+    // expansion has the hygiene of wherever the body was written.
+    fn template(&self, stream: TokenStream) -> syn::Result<Vec<Piece>> {
+        let tokens: Vec<TokenTree> = stream.into_iter().collect();
+        let mut pieces = Vec::with_capacity(tokens.len());
+        let mut i = 0;
+        while i < tokens.len() {
+            match &tokens[i] {
+                TokenTree::Ident(prefix) if self.is_paste(&tokens, i) => {
+                    let prefix_name = prefix.to_string();
+                    pieces.push(Piece::Paste {
+                        prefix: prefix_name.trim_start_matches("r#").to_owned(),
+                        span: prefix.span(),
+                        site: tokens[i..i + 3].iter().cloned().collect(),
+                    });
+                    i += 3;
+                    continue;
+                }
+                TokenTree::Ident(prefix) if is_name_lookup(&tokens, i + 1) => {
+                    self.check_name_lookup(&tokens, i + 1)?;
+                    let prefix_name = prefix.to_string();
+                    pieces.push(Piece::Name {
+                        prefix: prefix_name.trim_start_matches("r#").to_owned(),
+                        span: prefix.span(),
+                    });
+                    i += 4;
+                    continue;
+                }
+                TokenTree::Punct(_) if is_name_lookup(&tokens, i) => {
+                    self.check_name_lookup(&tokens, i)?;
+                    pieces.push(Piece::Name {
+                        prefix: String::new(),
+                        span: tokens[i + 1].span(),
+                    });
+                    i += 3;
+                    continue;
+                }
+                TokenTree::Ident(ident) if *ident == self.var && !is_lifetime(&tokens, i) => {
+                    pieces.push(Piece::Number(ident.span()));
+                }
+                TokenTree::Punct(tilde)
+                    if tilde.as_char() == '~' && self.is_var(&tokens, i + 1) =>
+                {
+                    let site: TokenStream =
+                        tokens[i.saturating_sub(1)..i + 2].iter().cloned().collect();
+                    return Err(syn::Error::new_spanned(
+                        site,
+                        format!("`~{}` can only be pasted onto an identifier", self.var),
+                    ));
+                }
+                TokenTree::Group(group) => {
+                    let inner = self.template(group.stream())?;
+                    pieces.push(Piece::Group(group.clone(), inner));
+                }
+                tt => pieces.push(Piece::Token(tt.clone())),
+            }
+            i += 1;
+        }
+        Ok(pieces)
+    }
+
+    // Checks the `~{...}~` starting at `tokens[i]` reads `~{names[N]}~`, with
+    // the list given.
+    fn check_name_lookup(&self, tokens: &[TokenTree], i: usize) -> syn::Result<()> {
+        let site: TokenStream = tokens[i..tokens.len().min(i + 3)].iter().cloned().collect();
+        let expected = format!("expected `~{{names[{}]}}~`", self.var);
+        let lookup: Vec<TokenTree> = match &tokens[i + 1] {
+            TokenTree::Group(group) => group.stream().into_iter().collect(),
+            _ => unreachable!(),
+        };
+        let indexed_by_var = match lookup.as_slice() {
+            [TokenTree::Ident(names), TokenTree::Group(index)] => {
+                names == "names"
+                    && index.delimiter() == Delimiter::Bracket
+                    && matches!(
+                        index.stream().into_iter().collect::<Vec<_>>().as_slice(),
+                        [TokenTree::Ident(var)] if *var == self.var
+                    )
+            }
+            _ => false,
+        };
+        if !indexed_by_var || !is_tilde(tokens, i + 2) {
+            return Err(syn::Error::new_spanned(site, expected));
+        }
+        if self.names.is_none() {
+            return Err(syn::Error::new_spanned(
+                site,
+                "`names[..]` needs a `names = [...]` list after the range",
+            ));
+        }
+        Ok(())
+    }
+
+    fn is_var(&self, tokens: &[TokenTree], i: usize) -> bool {
+        matches!(tokens.get(i), Some(TokenTree::Ident(var)) if *var == self.var)
+    }
+
+    fn is_paste(&self, tokens: &[TokenTree], i: usize) -> bool {
+        match (tokens.get(i + 1), tokens.get(i + 2)) {
+            (Some(TokenTree::Punct(p)), Some(TokenTree::Ident(var))) => {
+                (p.as_char() == '~' || p.as_char() == '#') && *var == self.var
+            }
+            _ => false,
+        }
+    }
+}
+
+// A body prepared for substitution by `Seq::template`.
+enum Piece {
+    Token(TokenTree),
+    // The loop variable, replaced by the value.
+    Number(Span),
+    // `Prefix~N`, replaced by a single identifier.
+    Paste {
+        prefix: String,
+        span: Span,
+        site: TokenStream,
+    },
+    // `Prefix~{names[N]}~`, replaced by a single identifier, with an empty
+    // prefix when nothing was pasted onto.
+    Name {
+        prefix: String,
+        span: Span,
+    },
+    // A group with its contents prepared in turn.
+    Group(Group, Vec<Piece>),
+}
+
+// Appends the template's tokens for the value `n` to `out`. A negative `n` is
+// substituted as `-` and a literal in an invisible group, so that it stays one
+// operand in expressions like `N.pow(2)`, and cannot be pasted.
+fn instantiate(
+    template: &[Piece],
+    n: i128,
+    names: Option<&[String]>,
+    out: &mut Vec<TokenTree>,
+) -> syn::Result<()> {
+    for piece in template {
+        match piece {
+            Piece::Token(tt) => out.push(tt.clone()),
+            Piece::Number(span) => out.push(number(n, *span)),
+            Piece::Paste { site, .. } if n < 0 => {
+                return Err(syn::Error::new_spanned(
+                    site,
+                    format!("cannot paste the negative value {} onto an identifier", n),
+                ));
+            }
+            Piece::Paste { prefix, span, .. } => {
+                let pasted = Ident::new(&format!("{}{}", prefix, n), *span);
+                out.push(TokenTree::Ident(pasted));
+            }
+            // Checked against the range when the list was parsed.
+            Piece::Name { prefix, span } => {
+                let name = &names.expect("names were given")[n as usize];
+                let pasted = Ident::new(&format!("{}{}", prefix, name), *span);
+                out.push(TokenTree::Ident(pasted));
+            }
+            Piece::Group(group, inner) => {
+                let mut stream = Vec::with_capacity(inner.len());
+                instantiate(inner, n, names, &mut stream)?;
+                out.push(TokenTree::Group(regroup(
+                    group,
+                    stream.into_iter().collect(),
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+// Parses an integer literal with an optional leading `-`, returning its value
+// and the literal.
+fn parse_bound(input: ParseStream) -> syn::Result<(i128, LitInt)> {
+    let negative = input.parse::<Option<Token![-]>>()?.is_some();
+    let lit = input.parse::<LitInt>()?;
+    let magnitude = i128::from(lit.base10_parse::<u64>()?);
+    let value = if negative { -magnitude } else { magnitude };
+    if !lit.suffix().is_empty() {
+        let (min, max) = suffix_range(lit.suffix()).ok_or_else(|| {
+            syn::Error::new_spanned(
+                &lit,
+                format!("`{}` is not an integer type suffix", lit.suffix()),
+            )
+        })?;
+        if value < min || value > max {
+            return Err(syn::Error::new_spanned(
+                &lit,
+                format!("{} does not fit in `{}`", value, lit.suffix()),
+            ));
+        }
+    }
+    Ok((value, lit))
+}
+
+// The values of the integer type named by a literal suffix. A bound is at most
+// u64::MAX in magnitude, so the wider unsigned types take any positive one.
+fn suffix_range(suffix: &str) -> Option<(i128, i128)> {
+    let range = match suffix {
+        "u8" => (0, i128::from(u8::MAX)),
+        "u16" => (0, i128::from(u16::MAX)),
+        "u32" => (0, i128::from(u32::MAX)),
+        "u64" | "usize" | "u128" => (0, i128::MAX),
+        "i8" => (i128::from(i8::MIN), i128::from(i8::MAX)),
+        "i16" => (i128::from(i16::MIN), i128::from(i16::MAX)),
+        "i32" => (i128::from(i32::MIN), i128::from(i32::MAX)),
+        "i64" | "isize" => (i128::from(i64::MIN), i128::from(i64::MAX)),
+        "i128" => (i128::MIN, i128::MAX),
+        _ => return None,
+    };
+    Some(range)
+}
+
+// Parses `names = ["a", "b"]`, which needs an entry for every value of the
+// range. Each entry has to be able to end an identifier, and to start one.
+fn parse_names(input: ParseStream, start: i128, end: i128) -> syn::Result<Vec<String>> {
+    let key: Ident = input.parse()?;
+    if key != "names" {
+        return Err(syn::Error::new(
+            key.span(),
+            "expected `names = [...]` or a `{ ... }` body after the range",
+        ));
+    }
+    input.parse::<Token![=]>()?;
+    let content;
+    let brackets = bracketed!(content in input);
+    let lits = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+    let mut names = Vec::with_capacity(lits.len());
+    for lit in &lits {
+        let name = lit.value();
+        let mut chars = name.chars();
+        let valid = matches!(chars.next(), Some(c) if c == '_' || c.is_alphabetic())
+            && chars.all(|c| c == '_' || c.is_alphanumeric());
+        if !valid {
+            return Err(syn::Error::new_spanned(
+                lit,
+                format!("`{}` cannot be pasted into an identifier", name),
+            ));
+        }
+        names.push(name);
+    }
+    if start < 0 || end > names.len() as i128 {
+        return Err(syn::Error::new(
+            brackets.span,
+            format!(
+                "names has {} entries, so the range has to lie within 0..{0}",
+                names.len()
+            ),
+        ));
+    }
+    Ok(names)
+}
+
+// The value substituted for the loop variable, spanned at the variable.
+fn number(n: i128, span: Span) -> TokenTree {
+    let mut lit = Literal::u128_unsuffixed(n.unsigned_abs());
+    lit.set_span(span);
+    if n >= 0 {
+        return TokenTree::Literal(lit);
+    }
+    let mut minus = Punct::new('-', Spacing::Alone);
+    minus.set_span(span);
+    let tokens = vec![TokenTree::Punct(minus), TokenTree::Literal(lit)];
+    let mut group = Group::new(Delimiter::None, tokens.into_iter().collect());
+    group.set_span(span);
+    TokenTree::Group(group)
+}
+
+// Whether `tokens[i]` is the name of a lifetime such as `'N`.
+fn is_lifetime(tokens: &[TokenTree], i: usize) -> bool {
+    match i.checked_sub(1).map(|prev| &tokens[prev]) {
+        Some(TokenTree::Punct(p)) => p.as_char() == '\'' && p.spacing() == Spacing::Joint,
+        _ => false,
+    }
+}
+
+// Whether `tokens[i..]` starts with `~` and a braced group, which is taken to
+// be a `~{names[N]}~` lookup.
+fn is_name_lookup(tokens: &[TokenTree], i: usize) -> bool {
+    let braced =
+        |tt: &TokenTree| matches!(tt, TokenTree::Group(g) if g.delimiter() == Delimiter::Brace);
+    is_tilde(tokens, i) && tokens.get(i + 1).is_some_and(braced)
+}
+
+fn is_tilde(tokens: &[TokenTree], i: usize) -> bool {
+    matches!(tokens.get(i), Some(TokenTree::Punct(p)) if p.as_char() == '~')
+}
+
+// Matches `#`, a parenthesized group and `*` starting at `tokens[i]`,
+// returning the group's contents.
+fn repetition_at(tokens: &[TokenTree], i: usize) -> Option<TokenStream> {
+    match (tokens.get(i), tokens.get(i + 1), tokens.get(i + 2)) {
+        (
+            Some(TokenTree::Punct(pound)),
+            Some(TokenTree::Group(group)),
+            Some(TokenTree::Punct(star)),
+        ) if pound.as_char() == '#'
+            && group.delimiter() == Delimiter::Parenthesis
+            && star.as_char() == '*' =>
+        {
+            Some(group.stream())
+        }
+        _ => None,
+    }
+}
+
+fn regroup(original: &Group, stream: TokenStream) -> Group {
+    let mut group = Group::new(original.delimiter(), stream);
+    group.set_span(original.span());
+    group
+}
@@ -0,0 +1,40 @@
+// The loop variable is replaced by a plain integer literal with no type
+// suffix, so the same substitution is usable anywhere an integer literal is:
+// as an array length, as a const value, and as a match arm pattern.
+//
+// For example `[0u8; N]` with N = 3 expands to `[0u8; 3]` rather than
+// `[0u8; 3u64]`, which would not typecheck as a usize length.
+
+use seq::seq;
+
+seq!(N in 1..4 {
+    const LEN~N: usize = N;
+    const WIDE~N: u64 = N;
+
+    fn zeros~N() -> [u8; N] {
+        [0u8; N]
+    }
+});
+
+fn describe(n: u32) -> &'static str {
+    seq!(N in 0..3 {
+        match n {
+            #(
+                N => stringify!(N),
+            )*
+            _ => "many",
+        }
+    })
+}
+
+fn main() {
+    assert_eq!(LEN1 + LEN2 + LEN3, 6);
+    assert_eq!(WIDE3, 3u64);
+
+    assert_eq!(zeros1().len(), 1);
+    assert_eq!(zeros3(), [0u8; 3]);
+
+    assert_eq!(describe(0), "0");
+    assert_eq!(describe(2), "2");
+    assert_eq!(describe(7), "many");
+}
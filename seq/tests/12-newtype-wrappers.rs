@@ -0,0 +1,29 @@
+// Each iteration of the body may contain several items. Here every index
+// produces both a newtype struct with derives and an inherent impl for it,
+// with the pasted name used in both places.
+
+use seq::seq;
+
+seq!(N in 1..4 {
+    #[derive(Clone, Copy)]
+    pub struct Meters~N(pub f64);
+
+    impl Meters~N {
+        pub const SCALE: f64 = N as f64;
+
+        pub fn scaled(self) -> f64 {
+            self.0 * Self::SCALE
+        }
+    }
+});
+
+fn main() {
+    assert_eq!(Meters2::SCALE, 2.0);
+
+    let m = Meters3(1.5);
+    let copy = m;
+    assert_eq!(copy.scaled(), 4.5);
+    assert_eq!(m.0, 1.5);
+
+    assert_eq!(Meters1(7.0).scaled(), 7.0);
+}
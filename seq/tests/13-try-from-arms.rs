@@ -0,0 +1,41 @@
+// Repeated match arms can sit next to hand-written ones. Only the `#(...)*`
+// section is repeated, so the `_ => Err(())` fallback appears exactly once
+// after the generated arms.
+
+use seq::seq;
+use std::convert::TryFrom;
+
+#[derive(Debug, PartialEq)]
+enum Code {
+    C0,
+    C1,
+    C2,
+    C3,
+}
+
+seq!(N in 0..4 {
+    impl TryFrom<u8> for Code {
+        type Error = ();
+
+        fn try_from(v: u8) -> Result<Self, ()> {
+            match v {
+                #(
+                    N => Ok(Code::C~N),
+                )*
+                _ => Err(()),
+            }
+        }
+    }
+});
+
+fn main() {
+    assert_eq!(Code::try_from(0), Ok(Code::C0));
+    assert_eq!(Code::try_from(3), Ok(Code::C3));
+    assert_eq!(Code::try_from(4), Err(()));
+    assert_eq!(Code::try_from(255), Err(()));
+
+    for byte in 0..4u8 {
+        let code = Code::try_from(byte).unwrap();
+        assert_eq!(code as u8, byte);
+    }
+}
@@ -0,0 +1,47 @@
+// How many times a `#(...)*` section repeats is decided by the range alone;
+// substitution then replaces whatever occurrences of the loop variable the
+// section happens to contain. A section that never mentions the variable is
+// still repeated once per value, and every section in the body is repeated
+// over the full range.
+
+use seq::seq;
+
+// The variable is used only inside the repeated section.
+seq!(N in 0..3 {
+    const SQUARES: [u32; 3] = [#(N * N,)*];
+});
+
+// The variable is not used at all, so the copies are identical.
+seq!(N in 0..4 {
+    const ONES: [u8; 4] = [#(1,)*];
+});
+
+// Two separate sections in one body.
+seq!(N in 1..4 {
+    #[derive(Debug, PartialEq)]
+    enum Level {
+        #(
+            L~N,
+        )*
+    }
+
+    const WEIGHTS: [u32; 3] = [#(N * 100,)*];
+
+    fn level_of(i: u32) -> Option<Level> {
+        match i {
+            #(
+                N => Some(Level::L~N),
+            )*
+            _ => None,
+        }
+    }
+});
+
+fn main() {
+    assert_eq!(SQUARES, [0, 1, 4]);
+    assert_eq!(ONES, [1, 1, 1, 1]);
+
+    assert_eq!(WEIGHTS, [100, 200, 300]);
+    assert_eq!(level_of(2), Some(Level::L2));
+    assert_eq!(level_of(4), None);
+}
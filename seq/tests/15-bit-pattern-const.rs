@@ -0,0 +1,15 @@
+// The substituted literal can take part in arbitrary const expressions, here
+// a shift and a bitwise-or, while the constant's name is pasted.
+
+use seq::seq;
+
+seq!(N in 0..4 {
+    pub const PERM~N: u32 = (1 << N) | 0x80;
+});
+
+fn main() {
+    assert_eq!(PERM0, 0x81);
+    assert_eq!(PERM1, 0x82);
+    assert_eq!(PERM2, 0x84);
+    assert_eq!(PERM3, 0x88);
+}
@@ -0,0 +1,28 @@
+// Repeating a `const _: () = ...;` item turns every index into a check that
+// runs at compile time. Here a const fn is verified against a table for each
+// input; if any entry were wrong the crate would fail to build.
+
+use seq::seq;
+
+const fn fib(n: usize) -> u64 {
+    let mut a = 0;
+    let mut b = 1;
+    let mut i = 0;
+    while i < n {
+        let next = a + b;
+        a = b;
+        b = next;
+        i += 1;
+    }
+    a
+}
+
+const FIB_TABLE: [u64; 8] = [0, 1, 1, 2, 3, 5, 8, 13];
+
+seq!(N in 0..8 {
+    const _: () = {
+        assert!(fib(N) == FIB_TABLE[N]);
+    };
+});
+
+fn main() {}
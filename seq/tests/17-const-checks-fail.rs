@@ -0,0 +1,18 @@
+// Same as the previous test, but the table has a wrong entry at index 3. Only
+// the expansion for that index fails, and the error points at its assertion.
+
+use seq::seq;
+
+const fn double(n: usize) -> usize {
+    n * 2
+}
+
+const TABLE: [usize; 4] = [0, 2, 4, 7];
+
+seq!(N in 0..4 {
+    const _: () = {
+        assert!(double(N) == TABLE[N], "double does not match the table");
+    };
+});
+
+fn main() {}
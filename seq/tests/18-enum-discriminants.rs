@@ -0,0 +1,34 @@
+// Pasting and value substitution compose within one repeated line: each
+// iteration emits a pasted variant name with the index as its explicit
+// discriminant, keeping the trailing comma of the repeated section.
+
+use seq::seq;
+
+seq!(N in 0..4 {
+    #[repr(u8)]
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    enum Irq {
+        #(
+            Line~N = N,
+        )*
+    }
+});
+
+seq!(N in 1..4 {
+    #[derive(Copy, Clone)]
+    enum Scaled {
+        #(
+            Step~N = N * 10,
+        )*
+    }
+});
+
+fn main() {
+    assert_eq!(Irq::Line0 as u8, 0);
+    assert_eq!(Irq::Line1 as u8, 1);
+    assert_eq!(Irq::Line3 as u8, 3);
+    assert_eq!(std::mem::size_of::<Irq>(), 1);
+
+    assert_eq!(Scaled::Step1 as isize, 10);
+    assert_eq!(Scaled::Step3 as isize, 30);
+}
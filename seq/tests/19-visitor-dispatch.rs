@@ -0,0 +1,59 @@
+// A repeated match arm may paste the loop variable into more than one
+// identifier: here both the enum variant in the pattern and the visitor
+// method called in the arm's body.
+
+use seq::seq;
+
+#[derive(Copy, Clone)]
+pub enum Kind {
+    K0,
+    K1,
+    K2,
+}
+
+pub struct Node {
+    kind: Kind,
+    value: u32,
+}
+
+impl Node {
+    fn kind(&self) -> Kind {
+        self.kind
+    }
+}
+
+#[derive(Default)]
+struct Visitor {
+    log: Vec<String>,
+}
+
+impl Visitor {
+    fn visit0(&mut self, node: &Node) {
+        self.log.push(format!("literal {}", node.value));
+    }
+
+    fn visit1(&mut self, node: &Node) {
+        self.log.push(format!("call {}", node.value));
+    }
+
+    fn visit2(&mut self, node: &Node) {
+        self.log.push(format!("block {}", node.value));
+    }
+
+    seq!(N in 0..3 {
+        fn visit(&mut self, node: &Node) {
+            match node.kind() {
+                #(
+                    Kind::K~N => self.visit~N(node),
+                )*
+            }
+        }
+    });
+}
+
+fn main() {
+    let mut visitor = Visitor::default();
+    visitor.visit(&Node { kind: Kind::K2, value: 7 });
+    visitor.visit(&Node { kind: Kind::K0, value: 1 });
+    assert_eq!(visitor.log, ["block 7", "literal 1"]);
+}
@@ -0,0 +1,33 @@
+// Range bounds can be hex, octal or binary literals. The loop variable is
+// substituted by its value as a plain decimal literal regardless of how the
+// bounds were written, so `Reg~N` pastes to `Reg10`, not `Reg0xa`.
+
+use seq::seq;
+
+seq!(N in 0x00..0x10 {
+    const ALL: [u8; 16] = [#(N,)*];
+});
+
+seq!(N in 0b1000..=0b1010 {
+    #[derive(Debug, PartialEq)]
+    enum Reg {
+        #(
+            Reg~N = N,
+        )*
+    }
+});
+
+seq!(N in 0o6..0o10 {
+    const OCTAL~N: u32 = N;
+});
+
+fn main() {
+    assert_eq!(ALL.len(), 16);
+    assert_eq!(ALL[0], 0);
+    assert_eq!(ALL[15], 15);
+
+    assert_eq!(Reg::Reg8 as u8, 8);
+    assert_eq!(Reg::Reg10 as u8, 0b1010);
+
+    assert_eq!(OCTAL6 + OCTAL7, 13);
+}
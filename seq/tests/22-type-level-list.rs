@@ -0,0 +1,56 @@
+// A right-nested type such as `Cons<T0, Cons<T1, Cons<T2, Nil>>>` is built
+// from two sections: one opening every level and one closing them all again.
+// The closing section never mentions the variable, so it simply repeats its
+// `>` once per value.
+//
+//     seq!(N in 0..3 {
+//         type List = #(Cons<T~N, )* Nil #(>)*;
+//     });
+//
+// Parentheses cannot be split across sections like that, because a group
+// always has to be closed inside the section that opened it. Nested tuples
+// such as `(T0, (T1, (T2, ())))` can instead be built by handing a flat list
+// to a recursive macro_rules macro.
+
+use seq::seq;
+use std::marker::PhantomData;
+
+pub struct Nil;
+pub struct Cons<H, T>(PhantomData<(H, T)>);
+
+pub trait Len {
+    const LEN: usize;
+}
+
+impl Len for Nil {
+    const LEN: usize = 0;
+}
+
+impl<H, T: Len> Len for Cons<H, T> {
+    const LEN: usize = 1 + T::LEN;
+}
+
+pub struct T0;
+pub struct T1;
+pub struct T2;
+
+seq!(N in 0..3 {
+    type List = #(Cons<T~N, )* Nil #(>)*;
+});
+
+macro_rules! tuple_list {
+    () => { () };
+    ($head:ty, $($tail:ty,)*) => { ($head, tuple_list!($($tail,)*)) };
+}
+
+seq!(N in 0..3 {
+    type Tuple = tuple_list!(#(T~N,)*);
+});
+
+fn main() {
+    let _: PhantomData<List> = PhantomData::<Cons<T0, Cons<T1, Cons<T2, Nil>>>>;
+    assert_eq!(List::LEN, 3);
+
+    let tuple: Tuple = (T0, (T1, (T2, ())));
+    let (T0, (T1, (T2, ()))) = tuple;
+}
@@ -0,0 +1,37 @@
+// Each copy of the body may hold several items, here the two impls of the
+// sealed trait pattern: a marker impl of the private supertrait, which keeps
+// other crates from implementing the public trait, and the public impl itself
+// with an associated const set from the loop variable.
+//
+// The loop variable is replaced wherever it appears as an identifier, so the
+// associated const needs a different name than `N`.
+
+use seq::seq;
+
+mod private {
+    pub trait Sealed {}
+}
+
+pub trait Level: private::Sealed {
+    const INDEX: usize;
+}
+
+seq!(N in 0..4 {
+    pub struct Level~N;
+
+    impl private::Sealed for Level~N {}
+
+    impl Level for Level~N {
+        const INDEX: usize = N;
+    }
+});
+
+fn index_of<L: Level>(_: L) -> usize {
+    L::INDEX
+}
+
+fn main() {
+    assert_eq!(Level0::INDEX, 0);
+    assert_eq!(<Level3 as Level>::INDEX, 3);
+    assert_eq!(index_of(Level2), 2);
+}
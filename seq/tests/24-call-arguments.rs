@@ -0,0 +1,20 @@
+// The loop variable may be an argument anywhere inside calls, nested macro
+// invocations and method chains. Each substituted literal keeps the span of
+// the `N` it replaces, so that errors about it point into the body.
+
+use seq::seq;
+
+fn get(i: u32) -> u32 {
+    i
+}
+
+fn main() {
+    let mut seen = Vec::new();
+    seq!(N in 1..4 {
+        assert_eq!(get(N), N);
+        assert_eq!(format!("{}", vec![N; N].len()), N.to_string());
+        seen.push(get(N).pow(N).checked_sub(N).unwrap_or(N));
+        debug_assert!(matches!(get(N), N), "{}", stringify!(N));
+    });
+    assert_eq!(seen, [0, 2, 24]);
+}
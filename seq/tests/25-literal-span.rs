@@ -0,0 +1,12 @@
+// A type error in a substituted literal is reported at the loop variable it
+// replaced, rather than at the macro invocation.
+
+use seq::seq;
+
+seq!(N in 0..1 {
+    fn name() -> &'static str {
+        N
+    }
+});
+
+fn main() {}
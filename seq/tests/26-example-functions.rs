@@ -0,0 +1,20 @@
+// Substitution leaves the rest of the body alone, so realistic function
+// bodies survive it: here a `vec![N; N]` invocation, where the literal is used
+// both as the element and as the length, and a turbofish on `sum`.
+
+use seq::seq;
+
+seq!(N in 0..5 {
+    pub fn example~N() -> u32 {
+        let v = vec![N; N];
+        v.iter().sum::<u32>()
+    }
+});
+
+fn main() {
+    assert_eq!(example0(), 0);
+    assert_eq!(example3(), 9);
+
+    let total: u32 = seq!(N in 0..5 { 0 #(+ example~N())* });
+    assert_eq!(total, 1 + 4 + 9 + 16);
+}
@@ -0,0 +1,9 @@
+// Problems with the input are reported as compile errors rather than as a
+// panic inside the macro. An inclusive range ending at u64::MAX has one value
+// past the end that cannot be represented.
+
+use seq::seq;
+
+seq!(N in 0..=18446744073709551615 {});
+
+fn main() {}
@@ -0,0 +1,20 @@
+// A substituted literal can be the argument of a method call on another
+// literal, and const methods such as `saturating_add` and `wrapping_mul`
+// evaluate at compile time as usual.
+
+use seq::seq;
+
+seq!(N in 0..8 {
+    const S~N: u8 = 250u8.saturating_add(N as u8);
+    const W~N: u8 = 100u8.wrapping_mul(N as u8);
+});
+
+fn main() {
+    assert_eq!(S0, 250);
+    assert_eq!(S3, 253);
+    assert_eq!(S5, 255);
+    assert_eq!(S7, 255);
+
+    assert_eq!(W2, 200);
+    assert_eq!(W3, 44);
+}
@@ -0,0 +1,20 @@
+// Only the loop variable itself and identifiers pasted with `~` are
+// rewritten. The contents of string, character and byte string literals are
+// never touched, nor is a lifetime that happens to share the variable's name.
+//
+// Any other identifier equal to the loop variable is replaced, so the name is
+// reserved within the body; pick one that is not used for anything else.
+
+use seq::seq;
+
+seq!(N in 0..2 {
+    #[allow(non_snake_case)]
+    fn label~N<'N>(prefix: &'N str) -> (&'N str, String, char, &'static [u8]) {
+        (prefix, format!("N = {}", N), 'N', b"N")
+    }
+});
+
+fn main() {
+    assert_eq!(label0("x"), ("x", "N = 0".to_owned(), 'N', &b"N"[..]));
+    assert_eq!(label1("y").1, "N = 1");
+}
@@ -0,0 +1,22 @@
+// seq! has a single loop variable, but a second one comes from a nested
+// invocation. The outer section only substitutes its own variable, leaving the
+// inner `#(...)*` for the inner seq! to repeat, so each row is built by an
+// inner loop over the columns with the row already filled in.
+
+use seq::seq;
+
+seq!(ROW in 0..3 {
+    const TRANS: [[u8; 3]; 3] = [
+        #(
+            seq!(COL in 0..3 {
+                [ #( (ROW * 3 + COL) as u8, )* ]
+            }),
+        )*
+    ];
+});
+
+fn main() {
+    assert_eq!(TRANS[0], [0, 1, 2]);
+    assert_eq!(TRANS[2][1], 7);
+    assert_eq!(TRANS, [[0, 1, 2], [3, 4, 5], [6, 7, 8]]);
+}
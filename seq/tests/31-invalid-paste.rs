@@ -0,0 +1,11 @@
+// Only an identifier can have the loop variable pasted onto it. Anything else
+// before `~N`, such as a literal, is reported at the paste site instead of
+// producing a malformed token.
+
+use seq::seq;
+
+seq!(N in 0..2 {
+    const VALUE: u32 = 1~N;
+});
+
+fn main() {}
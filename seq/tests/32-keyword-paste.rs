@@ -0,0 +1,20 @@
+// Pasting onto a keyword or a raw identifier produces a plain identifier:
+// `fn~N` and `r#fn~N` both paste into `fn0`, which is not a keyword.
+
+use seq::seq;
+
+seq!(N in 0..2 {
+    fn fn~N() -> u32 {
+        N
+    }
+
+    fn r#type~N() -> u32 {
+        N + 10
+    }
+});
+
+fn main() {
+    assert_eq!(fn0(), 0);
+    assert_eq!(fn1(), 1);
+    assert_eq!(type1(), 11);
+}
@@ -0,0 +1,34 @@
+// Substitution and pasting leave `async fn` and `.await` intact, so a family of
+// async handlers can be generated like any other functions.
+
+use seq::seq;
+use std::future::Future;
+use std::pin::pin;
+use std::task::{Context, Poll, Waker};
+
+async fn compute(n: u32) -> u32 {
+    n * 10
+}
+
+seq!(N in 0..3 {
+    pub async fn handle~N() -> u32 {
+        compute(N).await + N
+    }
+});
+
+// The handlers never wait on anything, so the first poll completes them.
+fn block_on<F: Future>(future: F) -> F::Output {
+    let mut future = pin!(future);
+    let mut cx = Context::from_waker(Waker::noop());
+    loop {
+        if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+            return output;
+        }
+    }
+}
+
+fn main() {
+    assert_eq!(block_on(handle0()), 0);
+    assert_eq!(block_on(handle2()), 22);
+    assert_eq!(block_on(async { handle1().await + handle2().await }), 33);
+}
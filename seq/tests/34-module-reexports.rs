@@ -0,0 +1,28 @@
+// Pasted names work across module and `use` boundaries. Each copy of the body
+// declares a numbered module and re-exports its constant under a numbered name
+// with `pub(super)` visibility, so the registry's parent can reach them.
+//
+// A re-export cannot be more visible than the item itself, so the constants
+// are `pub(crate)` rather than `pub(super)`, which would stop at the registry.
+
+mod registry {
+    use seq::seq;
+
+    seq!(N in 0..4 {
+        mod p~N {
+            pub(crate) const ID: u32 = N;
+        }
+
+        pub(super) use p~N::ID as ID~N;
+    });
+
+    pub fn sum() -> u32 {
+        ID0 + ID1 + ID2 + ID3
+    }
+}
+
+fn main() {
+    assert_eq!(registry::ID0, 0);
+    assert_eq!(registry::ID2, 2);
+    assert_eq!(registry::sum(), 6);
+}
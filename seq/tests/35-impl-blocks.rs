@@ -0,0 +1,30 @@
+// Without a `#(...)*` section the whole body is repeated, so every copy is a
+// complete, independent item: here one `impl` block per type, with the type
+// name pasted and the loop variable substituted inside the method.
+
+use seq::seq;
+
+pub trait Id {
+    fn id() -> usize;
+}
+
+seq!(N in 0..4 {
+    pub struct Type~N;
+});
+
+seq!(N in 0..4 {
+    impl Id for Type~N {
+        fn id() -> usize {
+            N
+        }
+    }
+});
+
+fn ids<A: Id, B: Id>() -> (usize, usize) {
+    (A::id(), B::id())
+}
+
+fn main() {
+    assert_eq!(Type2::id(), 2);
+    assert_eq!(ids::<Type0, Type3>(), (0, 3));
+}
@@ -0,0 +1,16 @@
+// `if`/`else` expressions over the substituted literal are evaluated at
+// compile time like any other const expression.
+
+use seq::seq;
+
+seq!(N in 0..4 {
+    const FLAG~N: bool = if N % 2 == 0 { true } else { false };
+    const PARITY~N: &str = if N % 2 == 0 { "even" } else { "odd" };
+});
+
+fn main() {
+    assert!(FLAG0);
+    assert!(FLAG2);
+    assert!(!FLAG3);
+    assert_eq!(PARITY1, "odd");
+}
@@ -0,0 +1,34 @@
+// The body is taken as raw tokens between the braces, not as a single
+// expression or item, so any sequence of items can be repeated: here a
+// struct, its impl, a free function, a constant and a type alias per value.
+// The braces around the body are not part of the output.
+
+use seq::seq;
+
+seq!(N in 1..3 {
+    #[derive(Debug, Default, PartialEq)]
+    pub struct Slot~N {
+        value: u32,
+    }
+
+    impl Slot~N {
+        pub const fn width() -> u32 {
+            N * 8
+        }
+    }
+
+    pub fn make~N() -> Slot~N {
+        Slot~N { value: N }
+    }
+
+    pub const WIDTH~N: u32 = Slot~N::width();
+
+    pub type Alias~N = Slot~N;
+});
+
+fn main() {
+    assert_eq!(make1(), Slot1 { value: 1 });
+    assert_eq!(make2().value, 2);
+    assert_eq!(WIDTH2, 16);
+    assert_eq!(Alias1::default(), Slot1::default());
+}
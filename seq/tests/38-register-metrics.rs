@@ -0,0 +1,48 @@
+// Registration code for observability usually repeats one statement per
+// metric, pasting the static's name and building the metric's name with
+// `concat!` and `stringify!`. The loop variable is substituted inside both
+// macro invocations before they expand, so each metric gets its own name.
+
+use seq::seq;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+pub struct Gauge(AtomicU64);
+
+impl Gauge {
+    pub const fn new(value: u64) -> Self {
+        Gauge(AtomicU64::new(value))
+    }
+
+    pub fn get(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Default)]
+pub struct Registry {
+    metrics: Vec<(&'static str, &'static Gauge)>,
+}
+
+impl Registry {
+    pub fn register(&mut self, name: &'static str, gauge: &'static Gauge) {
+        self.metrics.push((name, gauge));
+    }
+}
+
+static GAUGE0: Gauge = Gauge::new(10);
+static GAUGE1: Gauge = Gauge::new(11);
+static GAUGE2: Gauge = Gauge::new(12);
+
+fn main() {
+    let mut metrics = Registry::default();
+
+    seq!(N in 0..3 {
+        metrics.register(concat!("metric_", stringify!(N)), &GAUGE~N);
+    });
+
+    let names: Vec<_> = metrics.metrics.iter().map(|(name, _)| *name).collect();
+    assert_eq!(names, ["metric_0", "metric_1", "metric_2"]);
+
+    let values: Vec<_> = metrics.metrics.iter().map(|(_, gauge)| gauge.get()).collect();
+    assert_eq!(values, [10, 11, 12]);
+}
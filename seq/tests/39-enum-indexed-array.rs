@@ -0,0 +1,32 @@
+// Every repeated section in a body iterates over the same range, so an enum
+// and an array generated side by side line up: the variant with discriminant
+// N sits at index N of the array, and a cast variant can index it.
+
+use seq::seq;
+
+seq!(N in 0..4 {
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    pub enum Level {
+        #(
+            Level~N,
+        )*
+    }
+
+    pub const THRESHOLDS: [u32; 4] = [
+        #(
+            N * 10,
+        )*
+    ];
+});
+
+fn main() {
+    assert_eq!(THRESHOLDS[Level::Level0 as usize], 0);
+    assert_eq!(THRESHOLDS[Level::Level2 as usize], 20);
+    assert_eq!(THRESHOLDS[Level::Level3 as usize], 30);
+
+    let levels = [Level::Level0, Level::Level1, Level::Level2, Level::Level3];
+    for (i, level) in levels.iter().enumerate() {
+        assert_eq!(*level as usize, i);
+        assert_eq!(THRESHOLDS[*level as usize], i as u32 * 10);
+    }
+}
@@ -0,0 +1,35 @@
+// The loop variable is replaced by an unsuffixed integer literal, which is
+// exactly what a tuple index has to be, so `tup.N` and `self.N` access the
+// N-th element. A suffixed literal such as `2u64` would not parse there.
+
+use seq::seq;
+
+pub struct Channels(u8, u8, u8, u8);
+
+impl Channels {
+    pub fn total(&self) -> u32 {
+        let mut total = 0;
+        seq!(N in 0..4 {
+            total += self.N as u32;
+        });
+        total
+    }
+}
+
+fn main() {
+    let tup = (1u32, 2u32, 3u32, 4u32, 5u32);
+    let mut sum = 0;
+    seq!(N in 0..5 {
+        sum += tup.N;
+    });
+    assert_eq!(sum, 15);
+
+    let nested = ((10u32, 20u32), (30u32, 40u32));
+    let mut firsts = Vec::new();
+    seq!(N in 0..2 {
+        firsts.push(nested.N.0);
+    });
+    assert_eq!(firsts, [10, 30]);
+
+    assert_eq!(Channels(1, 2, 3, 250).total(), 256);
+}
@@ -0,0 +1,25 @@
+// Each repetition is its own block, so the items it defines are local to that
+// block and used right after them. The pasted names keep them apart anyway,
+// which matters once the braces are left out.
+
+use seq::seq;
+
+fn main() {
+    let mut sizes = Vec::new();
+    seq!(N in 0..3 {
+        {
+            #[derive(Debug, Default)]
+            struct Local~N([u8; N]);
+
+            let local = Local~N::default();
+            sizes.push(std::mem::size_of_val(&local));
+            assert_eq!(format!("{:?}", local), format!("Local{}({:?})", N, [0u8; N]));
+        }
+    });
+    assert_eq!(sizes, [0, 1, 2]);
+
+    seq!(N in 0..3 {
+        struct Unit~N;
+        let _ = Unit~N;
+    });
+}
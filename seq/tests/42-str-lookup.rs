@@ -0,0 +1,24 @@
+// A lookup from names to numbers as a match with one guarded arm per number.
+// `concat!` and `stringify!` see the substituted number, so each guard
+// compares against its own "itemN". The section repeats only the arms,
+// leaving the fallback arm and the function around it as written.
+
+use seq::seq;
+
+seq!(N in 0..4 {
+    fn index_of(name: &str) -> Option<u32> {
+        match name {
+            #(
+                n if n == concat!("item", stringify!(N)) => Some(N),
+            )*
+            _ => None,
+        }
+    }
+});
+
+fn main() {
+    assert_eq!(index_of("item0"), Some(0));
+    assert_eq!(index_of("item3"), Some(3));
+    assert_eq!(index_of("item4"), None);
+    assert_eq!(index_of("item"), None);
+}
@@ -0,0 +1,38 @@
+// Either bound may be negative. A negative value is substituted as `-` and an
+// unsuffixed literal grouped together, so it acts as a single operand wherever
+// it ends up, in expressions as well as in patterns.
+
+use seq::seq;
+
+seq!(N in -3..3 {
+    const AROUND_ZERO: [i32; 6] = [#(N,)*];
+});
+
+seq!(N in -5..=-2 {
+    const NEGATIVE: [i64; 4] = [#(N * 10,)*];
+});
+
+fn main() {
+    assert_eq!(AROUND_ZERO, [-3, -2, -1, 0, 1, 2]);
+    assert_eq!(NEGATIVE, [-50, -40, -30, -20]);
+
+    let mut magnitudes = Vec::new();
+    seq!(N in -2..=2 {
+        magnitudes.push((N as i8).abs());
+        assert_eq!(i32::abs(N) * i32::signum(N), N);
+        assert_eq!(2 - N + N, 2);
+    });
+    assert_eq!(magnitudes, [2, 1, 0, 1, 2]);
+
+    // Negative values can be matched against as well.
+    let sign = |x: i32| {
+        seq!(N in -1..=1 {
+            match x.signum() {
+                #(N => N,)*
+                _ => unreachable!(),
+            }
+        })
+    };
+    assert_eq!(sign(-7), -1);
+    assert_eq!(sign(0), 0);
+}
@@ -0,0 +1,10 @@
+// A negative value cannot be part of an identifier, so pasting it is an error
+// pointing at the paste.
+
+use seq::seq;
+
+seq!(N in -1..1 {
+    fn f~N() {}
+});
+
+fn main() {}
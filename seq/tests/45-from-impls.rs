@@ -0,0 +1,35 @@
+// One `From` impl per variant type, all converting into a shared enum. The
+// loop variable is pasted into the source type and into the variant alike,
+// so each impl wraps its own type in its own variant.
+
+use seq::seq;
+
+seq!(N in 0..3 {
+    #(
+        #[derive(Debug, PartialEq)]
+        pub struct Variant~N(pub u32);
+    )*
+
+    #[derive(Debug, PartialEq)]
+    pub enum AnyVariant {
+        #(
+            V~N(Variant~N),
+        )*
+    }
+
+    #(
+        impl From<Variant~N> for AnyVariant {
+            fn from(v: Variant~N) -> Self {
+                AnyVariant::V~N(v)
+            }
+        }
+    )*
+});
+
+fn main() {
+    assert_eq!(AnyVariant::from(Variant0(1)), AnyVariant::V0(Variant0(1)));
+    assert_eq!(AnyVariant::from(Variant1(2)), AnyVariant::V1(Variant1(2)));
+
+    let any: AnyVariant = Variant2(3).into();
+    assert!(matches!(any, AnyVariant::V2(Variant2(3))));
+}
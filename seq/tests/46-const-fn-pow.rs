@@ -0,0 +1,20 @@
+// `pow` takes its exponent as a u32. The substituted literal is unsuffixed, so
+// it is inferred as one there, and the same N can also pick the function name.
+// The generated functions are const, so the results are available to consts.
+
+use seq::seq;
+
+seq!(N in 0..4 {
+    const fn eval~N(x: u32) -> u32 {
+        x.pow(N)
+    }
+});
+
+const CUBE: u32 = eval3(3);
+const POWERS: [u32; 4] = [eval0(2), eval1(2), eval2(2), eval3(2)];
+
+fn main() {
+    assert_eq!(CUBE, 27);
+    assert_eq!(POWERS, [1, 2, 4, 8]);
+    assert_eq!(eval2(9), 81);
+}
@@ -0,0 +1,24 @@
+// A section inside square brackets repeats within the array literal, and the
+// comma inside the section leaves a trailing comma after the last element,
+// which an array literal accepts. The seq! invocation itself is the
+// initializer expression.
+
+use seq::seq;
+
+const fn compute(i: u32) -> u32 {
+    i * i + 1
+}
+
+const TABLE: [u32; 8] = seq!(N in 0..8 {
+    [ #( compute(N), )* ]
+});
+
+fn main() {
+    let squares: [u32; 8] = seq!(N in 0..8 { [#(compute(N),)*] });
+    assert_eq!(squares, [1, 2, 5, 10, 17, 26, 37, 50]);
+    assert_eq!(squares, TABLE);
+
+    // Brackets inside the section are repeated along with everything else.
+    let nested: [[u32; 2]; 3] = seq!(N in 0..3 { [#([N, compute(N)],)*] });
+    assert_eq!(nested, [[0, 1], [1, 2], [2, 5]]);
+}
@@ -0,0 +1,26 @@
+// Attributes on a repeated item are copied to every repetition as written,
+// including ones that only the linker looks at. Each static has its own pasted
+// name, which `#[no_mangle]` exports as is. The section name is nominal here.
+
+use seq::seq;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+static CALLS: AtomicUsize = AtomicUsize::new(0);
+
+extern "C" fn default_handler() {
+    CALLS.fetch_add(1, Ordering::Relaxed);
+}
+
+seq!(N in 0..16 {
+    #[link_section = ".vectors"]
+    #[no_mangle]
+    pub static VEC~N: extern "C" fn() = default_handler;
+});
+
+fn main() {
+    let vectors = [VEC0, VEC7, VEC15];
+    for vector in vectors.iter() {
+        vector();
+    }
+    assert_eq!(CALLS.load(Ordering::Relaxed), 3);
+}
@@ -0,0 +1,52 @@
+// Large ranges such as the 1024 entries of a lookup table. The body is
+// scanned once up front, and each value is then a single pass producing its
+// copy, so expansion time grows with the size of the output and no faster.
+
+use seq::seq;
+
+const fn crc_step(byte: u32) -> u32 {
+    let mut crc = byte;
+    let mut bit = 0;
+    while bit < 8 {
+        crc = if crc & 1 != 0 { (crc >> 1) ^ 0xedb8_8320 } else { crc >> 1 };
+        bit += 1;
+    }
+    crc
+}
+
+seq!(N in 0..1024 {
+    static TABLE: [u32; 1024] = [
+        #(
+            crc_step((N % 256) as u32) ^ (N / 256) as u32,
+        )*
+    ];
+
+    #[allow(dead_code)]
+    #[derive(Copy, Clone, Debug, PartialEq)]
+    #[repr(u16)]
+    pub enum Code {
+        #(
+            Code~N = N,
+        )*
+    }
+});
+
+seq!(N in 0..1024 {
+    fn code_at(i: u16) -> Option<Code> {
+        match i {
+            #(N => Some(Code::Code~N),)*
+            _ => None,
+        }
+    }
+});
+
+fn main() {
+    assert_eq!(TABLE[0], 0);
+    assert_eq!(TABLE[1], 0x7707_3096);
+    assert_eq!(TABLE[255], 0x2d02_ef8d);
+    assert_eq!(TABLE[256 + 1], 0x7707_3096 ^ 1);
+    assert_eq!(TABLE[1023], 0x2d02_ef8d ^ 3);
+    assert_eq!(code_at(1023), Some(Code::Code1023));
+    assert_eq!(code_at(1024), None);
+    assert_eq!(Code::Code512 as u16, 512);
+}
@@ -0,0 +1,52 @@
+// seq! adds no names of its own. A pasted identifier takes the span of its
+// prefix and a substituted number that of the loop variable, so everything in
+// the expansion resolves as if it had been written out where the body was.
+//
+// That holds when the body comes from another macro as well: items pasted
+// from a name the caller passed in are visible to the caller, while a local
+// the macro declares keeps the macro's hygiene and does not clash with the
+// caller's variable of the same name.
+
+use seq::seq;
+
+macro_rules! getters {
+    ($name:ident, $count:literal) => {
+        seq!(N in 0..$count {
+            pub fn $name~N() -> u32 {
+                N * 10
+            }
+        });
+    };
+}
+
+macro_rules! sum_to {
+    ($count:literal) => {{
+        let mut total = 0;
+        seq!(N in 0..$count {
+            total += N;
+        });
+        total
+    }};
+}
+
+getters!(tens, 3);
+
+seq!(N in 0..3 {
+    pub struct Handwritten~N;
+
+    impl Handwritten~N {
+        pub const INDEX: usize = N;
+    }
+});
+
+fn main() {
+    assert_eq!(tens0() + tens1() + tens2(), 30);
+
+    let total = 100;
+    assert_eq!(sum_to!(5), 10);
+    assert_eq!(total, 100);
+
+    let handwritten = [Handwritten0::INDEX, Handwritten1::INDEX, Handwritten2::INDEX];
+    assert_eq!(handwritten, [0, 1, 2]);
+    let _: Handwritten2 = Handwritten2;
+}
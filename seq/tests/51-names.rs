@@ -0,0 +1,46 @@
+// A list of names after the range, `names = [...]`, maps each value to a name.
+// `~{names[N]}~` in the body is replaced by the name at index N, pasted onto
+// an identifier written right before it like `~N` is, so methods can be
+// named after something other than their number while their bodies still
+// use it.
+
+use seq::seq;
+
+pub struct Channels;
+
+seq!(N in 0..3 names = ["alpha", "beta", "gamma"] {
+    impl Channels {
+        #(
+            pub fn get_~{names[N]}~() -> u32 {
+                N
+            }
+        )*
+    }
+});
+
+// Without anything pasted onto, the name stands on its own.
+#[derive(Debug, PartialEq)]
+pub enum Level {
+    Low,
+    Mid,
+    High,
+}
+
+seq!(N in 1..=2 names = ["Low", "Mid", "High"] {
+    fn level_of(n: u32) -> Option<Level> {
+        match n {
+            #(N => Some(Level::~{names[N]}~),)*
+            _ => None,
+        }
+    }
+});
+
+fn main() {
+    assert_eq!(Channels::get_alpha(), 0);
+    assert_eq!(Channels::get_beta(), 1);
+    assert_eq!(Channels::get_gamma(), 2);
+
+    assert_eq!(level_of(0), None);
+    assert_eq!(level_of(1), Some(Level::Mid));
+    assert_eq!(level_of(2), Some(Level::High));
+}
@@ -0,0 +1,22 @@
+// The names list needs an entry for every value in the range, each of which
+// has to fit in an identifier, and can only be indexed by the loop variable.
+
+use seq::seq;
+
+seq!(N in 0..4 names = ["a", "b", "c"] {
+    fn f~{names[N]}~() {}
+});
+
+seq!(N in 0..2 names = ["a", "b c"] {
+    fn f~{names[N]}~() {}
+});
+
+seq!(N in 0..2 names = ["a", "b"] {
+    fn f~{names[0]}~() {}
+});
+
+seq!(N in 0..2 {
+    fn f~{names[N]}~() {}
+});
+
+fn main() {}
@@ -0,0 +1,9 @@
+// An empty body expands to nothing, however long the range.
+
+use seq::seq;
+
+seq!(N in 0..4 {});
+
+fn main() {
+    seq!(N in 0..1000 {});
+}
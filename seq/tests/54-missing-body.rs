@@ -0,0 +1,12 @@
+// Leaving out the body is an error pointing at where it was expected, whether
+// the range ends the input or something else follows it.
+
+use seq::seq;
+
+seq!(N in 0..4);
+
+seq!(N in 0..4 => N);
+
+seq!(N in 0..4 N);
+
+fn main() {}
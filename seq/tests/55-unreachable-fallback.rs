@@ -0,0 +1,29 @@
+// A dispatch over the range with a fallback arm for every other index. The
+// arms are repeated while the `unreachable!` after them, message included, is
+// left as written, so an index out of range panics with that message.
+
+use seq::seq;
+use std::panic;
+
+fn handle(n: u8) -> u32 {
+    u32::from(n) * 10
+}
+
+seq!(N in 0..4 {
+    fn dispatch(index: u8) -> u32 {
+        match index {
+            #(N => handle(N),)*
+            _ => unreachable!("index out of range"),
+        }
+    }
+});
+
+fn main() {
+    assert_eq!(dispatch(0), 0);
+    assert_eq!(dispatch(3), 30);
+
+    panic::set_hook(Box::new(|_| {}));
+    let payload = panic::catch_unwind(|| dispatch(4)).unwrap_err();
+    let message = payload.downcast_ref::<String>().unwrap();
+    assert_eq!(message, "internal error: entered unreachable code: index out of range");
+}
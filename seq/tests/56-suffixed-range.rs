@@ -0,0 +1,19 @@
+// Bounds copied from typed code may carry a suffix, on one or both ends. The
+// loop variable is still substituted without one, so it takes the type its
+// uses call for, here both u8 and u64.
+
+use seq::seq;
+
+fn main() {
+    let mut small: Vec<u8> = Vec::new();
+    let mut large: Vec<u64> = Vec::new();
+    seq!(N in 0u32..4u32 {
+        small.push(N);
+        large.push(N * 1_000_000_000_000);
+    });
+    assert_eq!(small, [0, 1, 2, 3]);
+    assert_eq!(large[3], 3_000_000_000_000);
+
+    let sum = seq!(N in -2i8..=2 { 0 #(+ N)* });
+    assert_eq!(sum, 0);
+}
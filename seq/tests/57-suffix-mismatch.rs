@@ -0,0 +1,12 @@
+// Bounds with different suffixes are an error rather than a guess at which
+// type was meant, and a suffix has to be an integer type fitting its value.
+
+use seq::seq;
+
+seq!(N in 0u8..8u32 {});
+
+seq!(N in 0..8f32 {});
+
+seq!(N in -1u8..8 {});
+
+fn main() {}
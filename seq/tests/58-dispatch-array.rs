@@ -0,0 +1,27 @@
+// Two sections in one body: the first defines a handler per value, the second
+// collects them into an array of function pointers. Each handler is cast to
+// the pointer type, since the array needs one type while every function item
+// has its own. Nothing in the array is computed at run time.
+
+use seq::seq;
+
+seq!(N in 0..4 {
+    #(
+        fn handler~N(x: u32) -> u32 {
+            x + N
+        }
+    )*
+
+    const DISPATCH: [fn(u32) -> u32; 4] = [
+        #(handler~N as fn(u32) -> u32,)*
+    ];
+});
+
+fn main() {
+    assert_eq!(DISPATCH[3](10), 13);
+    assert_eq!(DISPATCH[0](10), 10);
+
+    let results: Vec<u32> = DISPATCH.iter().map(|handler| handler(1)).collect();
+    assert_eq!(results, [1, 2, 3, 4]);
+    assert_eq!(handler2(5), 7);
+}
@@ -1,14 +1,62 @@
 #[test]
 fn tests() {
     let t = trybuild::TestCases::new();
-    //t.pass("tests/01-parse-header.rs");
-    //t.pass("tests/02-parse-body.rs");
-    //t.compile_fail("tests/03-expand-four-errors.rs");
-    //t.pass("tests/04-paste-ident.rs");
-    //t.pass("tests/05-repeat-section.rs");
-    //t.pass("tests/06-make-work-in-function.rs");
-    //t.pass("tests/07-init-array.rs");
-    //t.pass("tests/08-inclusive-range.rs");
-    //t.compile_fail("tests/09-ident-span.rs");
-    //t.pass("tests/10-interaction-with-macrorules.rs");
+    t.pass("tests/01-parse-header.rs");
+    t.pass("tests/02-parse-body.rs");
+    t.compile_fail("tests/03-expand-four-errors.rs");
+    t.pass("tests/04-paste-ident.rs");
+    t.pass("tests/05-repeat-section.rs");
+    t.pass("tests/06-make-work-in-function.rs");
+    t.pass("tests/07-init-array.rs");
+    t.pass("tests/08-inclusive-range.rs");
+    t.compile_fail("tests/09-ident-span.rs");
+    t.pass("tests/10-interaction-with-macrorules.rs");
+    t.pass("tests/11-literal-positions.rs");
+    t.pass("tests/12-newtype-wrappers.rs");
+    t.pass("tests/13-try-from-arms.rs");
+    t.pass("tests/14-independent-repetition.rs");
+    t.pass("tests/15-bit-pattern-const.rs");
+    t.pass("tests/16-const-checks.rs");
+    t.compile_fail("tests/17-const-checks-fail.rs");
+    t.pass("tests/18-enum-discriminants.rs");
+    t.pass("tests/19-visitor-dispatch.rs");
+    t.pass("tests/20-radix-bounds.rs");
+    t.pass("tests/21-crc-table.rs");
+    t.pass("tests/22-type-level-list.rs");
+    t.pass("tests/23-sealed-impls.rs");
+    t.pass("tests/24-call-arguments.rs");
+    t.compile_fail("tests/25-literal-span.rs");
+    t.pass("tests/26-example-functions.rs");
+    t.compile_fail("tests/27-range-overflow.rs");
+    t.pass("tests/28-saturating-consts.rs");
+    t.pass("tests/29-untouched-tokens.rs");
+    t.pass("tests/30-transition-table.rs");
+    t.compile_fail("tests/31-invalid-paste.rs");
+    t.pass("tests/32-keyword-paste.rs");
+    t.pass("tests/33-async-handlers.rs");
+    t.pass("tests/34-module-reexports.rs");
+    t.pass("tests/35-impl-blocks.rs");
+    t.pass("tests/36-if-consts.rs");
+    t.pass("tests/37-multiple-items.rs");
+    t.pass("tests/38-register-metrics.rs");
+    t.pass("tests/39-enum-indexed-array.rs");
+    t.pass("tests/40-tuple-index.rs");
+    t.pass("tests/41-local-types.rs");
+    t.pass("tests/42-str-lookup.rs");
+    t.pass("tests/43-negative-range.rs");
+    t.compile_fail("tests/44-negative-paste.rs");
+    t.pass("tests/45-from-impls.rs");
+    t.pass("tests/46-const-fn-pow.rs");
+    t.pass("tests/47-array-initializer.rs");
+    t.pass("tests/48-link-section-statics.rs");
+    t.pass("tests/49-large-range.rs");
+    t.pass("tests/50-hygiene.rs");
+    t.pass("tests/51-names.rs");
+    t.compile_fail("tests/52-names-invalid.rs");
+    t.pass("tests/53-empty-body.rs");
+    t.compile_fail("tests/54-missing-body.rs");
+    t.pass("tests/55-unreachable-fallback.rs");
+    t.pass("tests/56-suffixed-range.rs");
+    t.compile_fail("tests/57-suffix-mismatch.rs");
+    t.pass("tests/58-dispatch-array.rs");
 }
@@ -1,6 +1,6 @@
 #[test]
 fn tests() {
-    let t = trybuild::TestCases::new();
+    let _t = trybuild::TestCases::new();
     //t.pass("tests/01-parse-enum.rs");
     //t.compile_fail("tests/02-not-enum.rs");
     //t.compile_fail("tests/03-out-of-order.rs");